@@ -8,7 +8,11 @@ interface QuestionData {
     thematic: string;
   };
   thematic: string;
-  answer?: string;
+  index?: number;
+  /** Réponse sérialisée de l'enum `Answer` : "oui", { likert: 4 }, { choix: 2 }… */
+  answer?: string | { [kind: string]: unknown };
+  answer_label?: string;
+  skipped?: boolean;
 }
 
 interface ContactFormData {
@@ -18,8 +22,55 @@ interface ContactFormData {
   contact_email: string;
   contact_phone?: string;
   message?: string;
+  locale?: string;
+  template?: { id: string; title: string; version: string };
   questions?: QuestionData[];
-  scores?: { [key: string]: number };
+  thematics?: ThematicResult[];
+  eligibility?: EligibilityResult | null;
+}
+
+interface EligibilityResult {
+  verdict: 'eligible' | 'eligible-with-conditions' | 'not-yet';
+  required: CriterionCheck[];
+  conditions: CriterionCheck[];
+}
+
+interface CriterionCheck {
+  criterion:
+    | { type: 'global-score'; min: number; score: number | null }
+    | { type: 'completion'; min: number; completion: number }
+    | { type: 'thematic-score'; thematic: string; name: string; min: number; score: number | null }
+    | { type: 'answered-yes'; question: string; text: string }
+    | { type: 'not-assessable'; reference: string };
+  met: boolean;
+}
+
+interface ThematicResult {
+  id: string;
+  name: string;
+  order: number;
+  color: string;
+  icon?: string | null;
+  // null : thématique non évaluée (aucune réponse notée)
+  score: number | null;
+  feedback?: string | null;
+  stats: ThematicStats;
+  dimensions?: DimensionResult[];
+}
+
+interface DimensionResult {
+  id: string;
+  name: string;
+  score: number | null;
+  stats: ThematicStats;
+}
+
+interface ThematicStats {
+  answered: number;
+  unanswered: number;
+  skipped?: number;
+  unknown: number;
+  completion: number;
 }
 
 interface BrevoLinkCompanyPayload {
@@ -60,8 +111,8 @@ export default async function handler(
       contact_lastname: formData.contact_lastname,
       has_questions: !!formData.questions,
       questions_count: formData.questions?.length || 0,
-      has_scores: !!formData.scores,
-      scores_count: formData.scores ? Object.keys(formData.scores).length : 0,
+      has_thematics: !!formData.thematics,
+      thematics_count: formData.thematics?.length || 0,
     });
 
     // Valider les champs requis
@@ -530,16 +581,16 @@ export default async function handler(
     console.log('📝 [NOTE] Vérification des conditions pour créer la note...');
     console.log('📝 [NOTE] contactId:', contactId);
     console.log('📝 [NOTE] has_questions:', !!formData.questions);
-    console.log('📝 [NOTE] has_scores:', !!formData.scores);
+    console.log('📝 [NOTE] has_thematics:', !!formData.thematics);
     
-    if (contactId && formData.questions && formData.scores) {
+    if (contactId && formData.questions && formData.thematics) {
       console.log('📝 [NOTE] Conditions remplies, création de la note...');
       await createBrevoNote(brevoApiKey, contactId, formData, startupName);
     } else {
       console.warn('⚠️ [NOTE] Conditions non remplies pour créer la note');
       if (!contactId) console.warn('  - contactId manquant');
       if (!formData.questions) console.warn('  - questions manquantes');
-      if (!formData.scores) console.warn('  - scores manquants');
+      if (!formData.thematics) console.warn('  - résultats par thématique manquants');
     }
 
     const contactFullName = `${firstName} ${lastName}`.trim();
@@ -682,6 +733,26 @@ function escapeHtml(text: string): string {
   return text.replace(/[&<>"']/g, (m) => map[m]);
 }
 
+function formatScore(score: number | null): string {
+  return score === null ? 'non évalué' : `${Math.round(score)}%`;
+}
+
+function describeCriterion(check: CriterionCheck): string {
+  const criterion = check.criterion;
+  switch (criterion.type) {
+    case 'global-score':
+      return `Score global ≥ ${criterion.min}% (${formatScore(criterion.score)})`;
+    case 'completion':
+      return `Questions répondues ≥ ${criterion.min}% (${formatScore(criterion.completion)})`;
+    case 'thematic-score':
+      return `${escapeHtml(criterion.name)} ≥ ${criterion.min}% (${formatScore(criterion.score)})`;
+    case 'answered-yes':
+      return `« Oui » à : ${escapeHtml(criterion.text)}`;
+    case 'not-assessable':
+      return `Non évaluable avec ce questionnaire : ${escapeHtml(criterion.reference)}`;
+  }
+}
+
 async function createBrevoNote(
   apiKey: string,
   contactId: number,
@@ -693,7 +764,7 @@ async function createBrevoNote(
     contactId,
     startupName,
     questions_count: formData.questions?.length || 0,
-    scores_count: formData.scores ? Object.keys(formData.scores).length : 0,
+    thematics_count: formData.thematics?.length || 0,
   });
   
   try {
@@ -714,16 +785,44 @@ async function createBrevoNote(
 
     // Construire le contenu de la note en HTML simple
     let noteContent = `<b>Résultats du questionnaire Start to Scale</b><br><br>`;
-    noteContent += `<b>Startup:</b> ${escapeHtml(startupName)}<br><br>`;
+    noteContent += `<b>Startup:</b> ${escapeHtml(startupName)}<br>`;
+    if (formData.template) {
+      noteContent += `<b>Questionnaire:</b> ${escapeHtml(formData.template.title)} (${escapeHtml(formData.template.id)}, v${escapeHtml(formData.template.version)})<br>`;
+    }
+    if (formData.locale) {
+      noteContent += `<b>Langue:</b> ${escapeHtml(formData.locale)}<br>`;
+    }
+    noteContent += `<br>`;
+
+    // Ajouter le verdict d'éligibilité et les critères évalués
+    if (formData.eligibility) {
+      const verdicts = {
+        'eligible': 'Éligible',
+        'eligible-with-conditions': 'Éligible sous conditions',
+        'not-yet': 'Pas encore éligible',
+      };
+      noteContent += `<b>Éligibilité:</b> ${verdicts[formData.eligibility.verdict] ?? escapeHtml(formData.eligibility.verdict)}<br>`;
+      const checks = [
+        ...formData.eligibility.required.map((check) => ({ check, label: 'indispensable' })),
+        ...formData.eligibility.conditions.map((check) => ({ check, label: 'condition' })),
+      ];
+      for (const { check, label } of checks) {
+        noteContent += `- ${check.met ? '✓' : '✗'} ${describeCriterion(check)} (${label})<br>`;
+      }
+      noteContent += `<br>`;
+    }
 
-    // Ajouter les scores par thématique
-    if (formData.scores && Object.keys(formData.scores).length > 0) {
+    // Ajouter les scores par thématique, dans l'ordre du questionnaire
+    if (formData.thematics && formData.thematics.length > 0) {
       noteContent += `<b>Scores par thématique:</b><br>`;
-      const sortedThematics = Object.keys(formData.scores).sort();
+      const sortedThematics = [...formData.thematics].sort((a, b) => a.order - b.order);
       for (const thematic of sortedThematics) {
-        const score = formData.scores[thematic];
-        const percentage = Math.round(score);
-        noteContent += `- ${escapeHtml(thematic)}: ${percentage}%<br>`;
+        const stats = thematic.stats;
+        const completionText = ` (${stats.answered}/${stats.answered + stats.unanswered} réponses, ${stats.skipped ?? 0} passées, ${stats.unknown} « Je ne sais pas »)`;
+        noteContent += `- ${escapeHtml(thematic.name)}: ${formatScore(thematic.score)}${completionText}<br>`;
+        for (const dimension of thematic.dimensions ?? []) {
+          noteContent += `&nbsp;&nbsp;· ${escapeHtml(dimension.name)}: ${formatScore(dimension.score)}<br>`;
+        }
       }
       noteContent += `<br>`;
     }
@@ -731,9 +830,10 @@ async function createBrevoNote(
     // Ajouter les questions avec réponses par thématique
     if (Object.keys(questionsByThematic).length > 0) {
       noteContent += `<b>Questions et réponses:</b><br><br>`;
-      const sortedThematics = Object.keys(questionsByThematic).sort();
+      // Les questions arrivent dans l'ordre du questionnaire
+      const thematicNames = Object.keys(questionsByThematic);
       
-      for (const thematic of sortedThematics) {
+      for (const thematic of thematicNames) {
         const questions = questionsByThematic[thematic];
         noteContent += `<b>${escapeHtml(thematic)}</b><br>`;
         
@@ -744,9 +844,7 @@ async function createBrevoNote(
           }
           
           if (qData.answer) {
-            const answerText = qData.answer === 'oui' ? 'Oui' : 
-                              qData.answer === 'non' ? 'Non' : 
-                              'Je ne sais pas';
+            const answerText = escapeHtml(qData.answer_label || JSON.stringify(qData.answer));
             noteContent += `R: <b>${answerText}</b><br>`;
           } else {
             noteContent += `R: Non répondu<br>`;
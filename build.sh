@@ -53,7 +53,7 @@ echo "✅ Formatting OK"
 
 # Verify lints
 echo "Checking lints with clippy..."
-if ! cargo clippy --all-targets --all-features -- -D warnings; then
+if ! cargo clippy --workspace --all-targets --all-features -- -D warnings; then
     echo "❌ Error: Clippy warnings detected."
     echo "💡 Fix warnings before building."
     exit 1
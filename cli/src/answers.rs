@@ -0,0 +1,316 @@
+use hub_survey_core::models::{Answer, AnswerKind, ChoiceOption, Question};
+use hub_survey_core::survey::Survey;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Réponses d'une startup lues dans un fichier
+#[derive(Debug, PartialEq)]
+pub struct Respondent {
+    pub label: String,
+    pub answers: HashMap<String, Answer>,
+}
+
+/// Lit un fichier de réponses `.json` ou `.csv`
+pub fn read_file(path: &Path, survey: &Survey) -> Result<Vec<Respondent>, String> {
+    let content =
+        std::fs::read_to_string(path).map_err(|err| format!("{} : {}", path.display(), err))?;
+    let label = path
+        .file_stem()
+        .map(|stem| stem.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    let extension = path
+        .extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase());
+
+    match extension.as_deref() {
+        Some("json") => read_json(&content, &label, survey),
+        Some("csv") => read_csv(&content, survey),
+        _ => Err("format non reconnu, .json ou .csv attendu".to_string()),
+    }
+    .map_err(|err| format!("{} : {}", path.display(), err))
+}
+
+/// Réponses JSON : soit un objet `{ "<id de question>": <réponse> }` pour une
+/// startup, soit un tableau `[{ "respondent": "...", "answers": { ... } }]`
+fn read_json(content: &str, label: &str, survey: &Survey) -> Result<Vec<Respondent>, String> {
+    let value: Value = serde_json::from_str(content).map_err(|err| err.to_string())?;
+    match value {
+        Value::Object(answers) => Ok(vec![Respondent {
+            label: label.to_string(),
+            answers: json_answers(&answers, survey)?,
+        }]),
+        Value::Array(items) => items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| {
+                let label = item
+                    .get("respondent")
+                    .and_then(Value::as_str)
+                    .map(str::to_string)
+                    .unwrap_or_else(|| format!("{} #{}", label, index + 1));
+                let answers = item
+                    .get("answers")
+                    .and_then(Value::as_object)
+                    .ok_or_else(|| format!("{} : champ « answers » manquant", label))?;
+                Ok(Respondent {
+                    answers: json_answers(answers, survey)
+                        .map_err(|err| format!("{} : {}", label, err))?,
+                    label,
+                })
+            })
+            .collect(),
+        _ => Err("objet ou tableau JSON attendu".to_string()),
+    }
+}
+
+/// Une réponse JSON est soit la sérialisation de `Answer` (`"oui"`,
+/// `{"likert": 4}`…), soit une valeur saisie comme dans un tableur (`4`, `"MVP"`)
+fn json_answers(
+    values: &serde_json::Map<String, Value>,
+    survey: &Survey,
+) -> Result<HashMap<String, Answer>, String> {
+    let mut answers = HashMap::new();
+    for (id, value) in values {
+        let question = find_question(survey, id)?;
+        let answer = match serde_json::from_value::<Answer>(value.clone()) {
+            Ok(answer) if question.kind.accepts(&answer) => Some(answer),
+            _ => {
+                let input = match value {
+                    Value::String(text) => text.clone(),
+                    Value::Null => String::new(),
+                    other => other.to_string(),
+                };
+                parse_answer(&question.kind, &input).map_err(|err| format!("{} : {}", id, err))?
+            }
+        };
+        if let Some(answer) = answer {
+            answers.insert(id.clone(), answer);
+        }
+    }
+    Ok(answers)
+}
+
+/// Réponses CSV, une startup par ligne : la première ligne donne les
+/// identifiants des questions, une première colonne non reconnue (`startup`…)
+/// nomme la startup. Séparateur `,` ou `;`.
+fn read_csv(content: &str, survey: &Survey) -> Result<Vec<Respondent>, String> {
+    let mut lines = content.lines().filter(|line| !line.trim().is_empty());
+    let header = lines.next().ok_or("fichier vide")?;
+    let separator = if header.matches(';').count() > header.matches(',').count() {
+        ';'
+    } else {
+        ','
+    };
+    let columns = split_csv_line(header, separator)?;
+
+    let mut label_column = None;
+    let mut questions = Vec::new();
+    for (position, column) in columns.iter().enumerate() {
+        match find_question(survey, column) {
+            Ok(question) => questions.push((position, question)),
+            Err(_) if position == 0 => label_column = Some(0),
+            Err(err) => return Err(err),
+        }
+    }
+
+    lines
+        .enumerate()
+        .map(|(row, line)| {
+            let cells = split_csv_line(line, separator)?;
+            let label = label_column
+                .and_then(|position| cells.get(position))
+                .filter(|label| !label.is_empty())
+                .cloned()
+                .unwrap_or_else(|| format!("ligne {}", row + 2));
+            let mut answers = HashMap::new();
+            for (position, question) in &questions {
+                let cell = cells.get(*position).map(String::as_str).unwrap_or_default();
+                if let Some(answer) = parse_answer(&question.kind, cell)
+                    .map_err(|err| format!("ligne {}, {} : {}", row + 2, question.id, err))?
+                {
+                    answers.insert(question.id.clone(), answer);
+                }
+            }
+            Ok(Respondent { label, answers })
+        })
+        .collect()
+}
+
+/// Découpe une ligne CSV ; les champs entre guillemets peuvent contenir le
+/// séparateur et des guillemets doublés
+fn split_csv_line(line: &str, separator: char) -> Result<Vec<String>, String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' if quoted && chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            '"' => quoted = !quoted,
+            c if c == separator && !quoted => fields.push(std::mem::take(&mut field)),
+            c => field.push(c),
+        }
+    }
+    if quoted {
+        return Err(format!("guillemet non fermé : {}", line));
+    }
+    fields.push(field);
+    Ok(fields.into_iter().map(|f| f.trim().to_string()).collect())
+}
+
+fn find_question<'a>(survey: &'a Survey, id: &str) -> Result<&'a Question, String> {
+    survey
+        .questions()
+        .iter()
+        .find(|question| question.id == id)
+        .ok_or_else(|| format!("question inconnue : {}", id))
+}
+
+/// Interprète une réponse saisie au clavier ou lue dans un tableur, selon le
+/// type de la question ; une saisie vide laisse la question sans réponse
+pub fn parse_answer(kind: &AnswerKind, input: &str) -> Result<Option<Answer>, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Ok(None);
+    }
+    let lower = input.to_lowercase();
+    if kind.is_scored()
+        && matches!(
+            lower.as_str(),
+            "?" | "je-ne-sais-pas" | "je ne sais pas" | "jnsp" | "i don't know"
+        )
+    {
+        return Ok(Some(Answer::JeNeSaisPas));
+    }
+
+    let answer = match kind {
+        AnswerKind::OuiNon => match lower.as_str() {
+            "oui" | "o" | "yes" | "y" => Answer::Oui,
+            "non" | "n" | "no" => Answer::Non,
+            _ => return Err(format!("« {} » : oui, non ou ? attendu", input)),
+        },
+        AnswerKind::Likert { .. } => Answer::Likert(
+            input
+                .parse()
+                .ok()
+                .filter(|value| (1..=5).contains(value))
+                .ok_or_else(|| format!("« {} » : valeur de 1 à 5 attendue", input))?,
+        ),
+        AnswerKind::SingleChoice { options } => Answer::Choice(choice_index(options, input)?),
+        AnswerKind::MultipleChoice { options } => {
+            let mut indices = input
+                .split([',', ';', '|'])
+                .map(str::trim)
+                .filter(|choice| !choice.is_empty())
+                .map(|choice| choice_index(options, choice))
+                .collect::<Result<Vec<_>, _>>()?;
+            indices.sort_unstable();
+            indices.dedup();
+            Answer::Choices(indices)
+        }
+        AnswerKind::Numeric { .. } => Answer::Number(
+            input
+                .replace(',', ".")
+                .parse::<f64>()
+                .ok()
+                .filter(|value| value.is_finite())
+                .ok_or_else(|| format!("« {} » : nombre attendu", input))?,
+        ),
+        AnswerKind::Text => Answer::Text(input.to_string()),
+    };
+    Ok(Some(answer))
+}
+
+/// Option désignée par son numéro (à partir de 1) ou par son libellé
+fn choice_index(options: &[ChoiceOption], input: &str) -> Result<usize, String> {
+    if let Some(index) = input
+        .parse::<usize>()
+        .ok()
+        .filter(|n| (1..=options.len()).contains(n))
+    {
+        return Ok(index - 1);
+    }
+    options
+        .iter()
+        .position(|option| option.label.to_lowercase() == input.to_lowercase())
+        .ok_or_else(|| {
+            format!(
+                "« {} » : numéro de 1 à {} ou libellé d'option attendu",
+                input,
+                options.len()
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hub_survey_core::scoring::DefaultScoring;
+
+    fn survey() -> Survey {
+        let sources = vec![(
+            "a.yaml".to_string(),
+            r#"thematic: "A"
+questions:
+  - id: "deck"
+    text: "Deck ?"
+  - id: "stade"
+    text: "Stade ?"
+    answer:
+      type: single-choice
+      options:
+        - label: "Idée"
+          score: 0
+        - label: "MVP"
+          score: 100
+  - id: "clients"
+    text: "Clients ?"
+    answer:
+      type: numeric
+      thresholds:
+        - min: 10
+          score: 100
+feedback:
+  - max: 100
+    message: "A"
+"#
+            .to_string(),
+        )];
+        Survey::try_from_sources(&sources, DefaultScoring).unwrap()
+    }
+
+    #[test]
+    fn csv_rows_are_parsed_by_question_kind() {
+        let csv = "startup;deck;stade;clients\nAcme;oui;MVP;\"12,5\"\n;?;1;\n";
+        let respondents = read_csv(csv, &survey()).unwrap();
+
+        assert_eq!(respondents[0].label, "Acme");
+        assert_eq!(respondents[0].answers["deck"], Answer::Oui);
+        assert_eq!(respondents[0].answers["stade"], Answer::Choice(1));
+        assert_eq!(respondents[0].answers["clients"], Answer::Number(12.5));
+        assert_eq!(respondents[1].label, "ligne 3");
+        assert_eq!(respondents[1].answers["deck"], Answer::JeNeSaisPas);
+        assert_eq!(respondents[1].answers["stade"], Answer::Choice(0));
+        assert!(!respondents[1].answers.contains_key("clients"));
+
+        let err = read_csv("deck,inconnue\noui,non\n", &survey()).unwrap_err();
+        assert!(err.contains("inconnue"));
+    }
+
+    #[test]
+    fn json_accepts_serialized_and_spreadsheet_answers() {
+        let json = r#"[{"respondent": "Acme", "answers": {"deck": "non", "stade": {"choix": 1}, "clients": 3}}]"#;
+        let respondents = read_json(json, "fichier", &survey()).unwrap();
+        assert_eq!(respondents[0].label, "Acme");
+        assert_eq!(respondents[0].answers["deck"], Answer::Non);
+        assert_eq!(respondents[0].answers["stade"], Answer::Choice(1));
+        assert_eq!(respondents[0].answers["clients"], Answer::Number(3.0));
+
+        let err = read_json(r#"{"deck": "peut-être"}"#, "fichier", &survey()).unwrap_err();
+        assert!(err.contains("deck"));
+    }
+}
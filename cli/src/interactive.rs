@@ -0,0 +1,107 @@
+use crate::answers::parse_answer;
+use hub_survey_core::i18n::Texts;
+use hub_survey_core::models::AnswerKind;
+use hub_survey_core::survey::Survey;
+use std::io::{self, BufRead, Write};
+
+/// Pose les questions visibles une à une sur la sortie d'erreur, pour garder
+/// la sortie standard aux résultats. Une ligne vide passe la question ; la fin
+/// de l'entrée arrête le questionnaire.
+pub fn run(survey: &Survey, texts: &Texts) -> Result<(), String> {
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    let mut current = if survey.is_visible(0) {
+        Some(0)
+    } else {
+        survey.next_visible(0)
+    };
+    let mut thematic = String::new();
+
+    while let Some(index) = current {
+        let data = survey.get_question(index);
+        if data.thematic != thematic {
+            thematic = data.thematic.clone();
+            eprintln!("\n== {} ==", thematic);
+        }
+        eprintln!(
+            "\n{} ({})",
+            data.question.text,
+            (texts.question_progress)(survey.visible_position(index), survey.visible_questions())
+        );
+        if let Some(description) = &data.question.description {
+            eprintln!("{}", description);
+        }
+        eprintln!("{}", hint(&data.question.kind, texts));
+
+        loop {
+            eprint!("> ");
+            io::stderr().flush().map_err(|err| err.to_string())?;
+            let Some(line) = lines.next() else {
+                eprintln!();
+                return Ok(());
+            };
+            let line = line.map_err(|err| err.to_string())?;
+            match parse_answer(&data.question.kind, &line) {
+                Ok(Some(answer)) => survey.answer_question(index, answer),
+                Ok(None) => survey.skip_question(index),
+                Err(err) => {
+                    eprintln!("{}", err);
+                    continue;
+                }
+            }
+            break;
+        }
+        current = survey.next_visible(index);
+    }
+    Ok(())
+}
+
+/// Réponses possibles, rappelées sous la question
+fn hint(kind: &AnswerKind, texts: &Texts) -> String {
+    let dont_know = format!("?  {}", texts.dont_know);
+    let lines = match kind {
+        AnswerKind::OuiNon => vec![
+            format!("{}  {}", texts.yes_key, texts.yes),
+            format!("{}  {}", texts.no_key, texts.no),
+            dont_know,
+        ],
+        AnswerKind::Likert {
+            min_label,
+            max_label,
+        } => vec![
+            format!(
+                "1 {} 5",
+                match (min_label, max_label) {
+                    (Some(min), Some(max)) => format!("({}) … ({})", min, max),
+                    _ => "…".to_string(),
+                }
+            ),
+            dont_know,
+        ],
+        AnswerKind::SingleChoice { options } | AnswerKind::MultipleChoice { options } => {
+            let mut lines: Vec<String> = options
+                .iter()
+                .enumerate()
+                .map(|(i, option)| format!("{}  {}", i + 1, option.label))
+                .collect();
+            if matches!(kind, AnswerKind::MultipleChoice { .. }) {
+                lines.push("1,3…".to_string());
+            }
+            lines.push(dont_know);
+            lines
+        }
+        AnswerKind::Numeric { unit, .. } => vec![
+            match unit {
+                Some(unit) => format!("0 … ({})", unit),
+                None => "0 …".to_string(),
+            },
+            dont_know,
+        ],
+        AnswerKind::Text => Vec::new(),
+    };
+    lines
+        .iter()
+        .map(|line| format!("  {}", line))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
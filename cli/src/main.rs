@@ -0,0 +1,234 @@
+//! `hub-survey` : mini audit Start to Scale en ligne de commande, posé dans le
+//! terminal ou noté à partir d'un fichier de réponses JSON ou CSV.
+
+mod answers;
+mod interactive;
+mod report;
+
+use hub_survey_core::i18n::Locale;
+use hub_survey_core::models::UnansweredPolicy;
+use hub_survey_core::scoring::DefaultScoring;
+use hub_survey_core::survey::{QuestionBundle, Survey};
+use report::Report;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+const USAGE: &str = "\
+Usage : hub-survey [options]
+
+Sans --answers, le questionnaire est posé dans le terminal.
+
+Options :
+  --answers <fichier>     Réponses à noter (.json ou .csv), d'une ou plusieurs startups
+  --template <id>         Questionnaire du catalogue (par défaut, le premier)
+  --questions <dossier>   Dossier des fichiers YAML (par défaut, le questionnaire intégré)
+  --lang <fr|en>          Langue du questionnaire (fr par défaut)
+  --unanswered <règle>    Questions sans réponse : count-as-zero (défaut), exclude
+                          ou block (erreur s'il reste des questions sans réponse)
+  --json                  Résultats au format JSON
+  --list                  Liste les questionnaires du catalogue
+  -h, --help              Affiche cette aide";
+
+#[derive(Debug, Default, PartialEq)]
+struct Options {
+    answers: Option<PathBuf>,
+    template: Option<String>,
+    questions: Option<PathBuf>,
+    locale: Locale,
+    unanswered_policy: UnansweredPolicy,
+    json: bool,
+    list: bool,
+}
+
+fn main() -> ExitCode {
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return ExitCode::SUCCESS;
+        }
+        Err(err) => {
+            eprintln!("{}\n\n{}", err, USAGE);
+            return ExitCode::from(2);
+        }
+    };
+
+    match run(&options) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("Erreur : {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Options de la ligne de commande, `None` lorsque l'aide est demandée
+fn parse_args(args: impl IntoIterator<Item = String>) -> Result<Option<Options>, String> {
+    let mut options = Options::default();
+    let mut args = args.into_iter();
+    while let Some(arg) = args.next() {
+        let mut value = || {
+            args.next()
+                .ok_or_else(|| format!("valeur manquante pour {}", arg))
+        };
+        match arg.as_str() {
+            "-h" | "--help" => return Ok(None),
+            "--answers" => options.answers = Some(PathBuf::from(value()?)),
+            "--template" => options.template = Some(value()?),
+            "--questions" => options.questions = Some(PathBuf::from(value()?)),
+            "--lang" => {
+                let code = value()?;
+                options.locale = Locale::from_code(&code)
+                    .ok_or_else(|| format!("langue inconnue : {}", code))?;
+            }
+            "--unanswered" => {
+                options.unanswered_policy = match value()?.as_str() {
+                    "count-as-zero" => UnansweredPolicy::CountAsZero,
+                    "exclude" => UnansweredPolicy::Exclude,
+                    "block" => UnansweredPolicy::Block,
+                    other => return Err(format!("règle inconnue : {}", other)),
+                }
+            }
+            "--json" => options.json = true,
+            "--list" => options.list = true,
+            other => return Err(format!("option inconnue : {}", other)),
+        }
+    }
+    Ok(Some(options))
+}
+
+fn run(options: &Options) -> Result<(), String> {
+    let bundle = match &options.questions {
+        Some(dir) => {
+            // Traductions rangées dans un sous-dossier par langue, comme `questions/`
+            let dir = dir.join(options.locale.questions_dir());
+            QuestionBundle::load(options.locale, |file| {
+                std::fs::read_to_string(dir.join(file)).map_err(|err| err.to_string())
+            })
+        }
+        None => QuestionBundle::embedded(options.locale),
+    }
+    .map_err(|err| err.to_string())?;
+
+    if options.list {
+        for template in &bundle.catalog.templates {
+            println!(
+                "{}\t{} (version {})",
+                template.id, template.title, template.version
+            );
+        }
+        return Ok(());
+    }
+
+    let template_id = match &options.template {
+        Some(id) => id.clone(),
+        None => bundle.catalog.templates[0].id.clone(),
+    };
+    let build_survey = || {
+        Survey::try_from_template(&bundle, &template_id, DefaultScoring)
+            .map(|survey| survey.with_unanswered_policy(options.unanswered_policy))
+            .map_err(|err| err.to_string())
+    };
+    let texts = options.locale.texts();
+
+    let reports = match &options.answers {
+        None => {
+            let survey = build_survey()?;
+            interactive::run(&survey, texts)?;
+            vec![Report::new(None, &survey)?]
+        }
+        Some(path) => {
+            let reference = build_survey()?;
+            answers::read_file(path, &reference)?
+                .into_iter()
+                .map(|respondent| {
+                    let survey = build_survey()?;
+                    survey.restore_answers(respondent.answers);
+                    Report::new(Some(respondent.label), &survey)
+                })
+                .collect::<Result<Vec<_>, _>>()?
+        }
+    };
+
+    if options.json {
+        let json = if options.answers.is_some() {
+            serde_json::to_string_pretty(&reports)
+        } else {
+            serde_json::to_string_pretty(&reports[0])
+        };
+        println!("{}", json.map_err(|err| err.to_string())?);
+    } else {
+        for report in &reports {
+            print!("{}", report.render(texts));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Option<Options>, String> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn options_are_parsed() {
+        assert_eq!(parse(&[]), Ok(Some(Options::default())));
+        assert_eq!(
+            parse(&[
+                "--answers",
+                "reponses.csv",
+                "--template",
+                "pre-seed",
+                "--lang",
+                "en",
+                "--unanswered",
+                "block",
+                "--json",
+            ]),
+            Ok(Some(Options {
+                answers: Some(PathBuf::from("reponses.csv")),
+                template: Some("pre-seed".to_string()),
+                locale: Locale::En,
+                unanswered_policy: UnansweredPolicy::Block,
+                json: true,
+                ..Options::default()
+            }))
+        );
+        assert_eq!(parse(&["--json", "--help"]), Ok(None));
+    }
+
+    #[test]
+    fn unanswered_policies_are_recognized() {
+        for (value, policy) in [
+            ("count-as-zero", UnansweredPolicy::CountAsZero),
+            ("exclude", UnansweredPolicy::Exclude),
+            ("block", UnansweredPolicy::Block),
+        ] {
+            let options = parse(&["--unanswered", value]).unwrap().unwrap();
+            assert_eq!(options.unanswered_policy, policy);
+        }
+        assert_eq!(
+            parse(&["--unanswered", "ignore"]),
+            Err("règle inconnue : ignore".to_string())
+        );
+    }
+
+    #[test]
+    fn invalid_arguments_are_rejected() {
+        assert_eq!(
+            parse(&["--template"]),
+            Err("valeur manquante pour --template".to_string())
+        );
+        assert_eq!(
+            parse(&["--lang", "de"]),
+            Err("langue inconnue : de".to_string())
+        );
+        assert_eq!(
+            parse(&["reponses.csv"]),
+            Err("option inconnue : reponses.csv".to_string())
+        );
+    }
+}
@@ -0,0 +1,130 @@
+use hub_survey_core::i18n::Texts;
+use hub_survey_core::models::SurveyResults;
+use hub_survey_core::survey::Survey;
+use serde::Serialize;
+use std::fmt::Write;
+
+/// Résultats d'une startup, affichés en texte ou exportés en JSON
+#[derive(Debug, Serialize)]
+pub struct Report {
+    /// Nom de la startup, absent en mode interactif
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub respondent: Option<String>,
+    pub template: Option<String>,
+    pub version: Option<String>,
+    pub results: SurveyResults,
+}
+
+impl Report {
+    pub fn new(respondent: Option<String>, survey: &Survey) -> Result<Self, String> {
+        let results = survey.get_results().map_err(|incomplete| {
+            format!(
+                "{}{} questions sans réponse",
+                respondent
+                    .as_ref()
+                    .map(|label| format!("{} : ", label))
+                    .unwrap_or_default(),
+                incomplete.unanswered.len()
+            )
+        })?;
+        Ok(Self {
+            respondent,
+            template: survey.template().map(|template| template.id.clone()),
+            version: survey.template().map(|template| template.version.clone()),
+            results,
+        })
+    }
+
+    pub fn render(&self, texts: &Texts) -> String {
+        let results = &self.results;
+        let mut out = String::new();
+        if let Some(respondent) = &self.respondent {
+            let _ = writeln!(out, "# {}", respondent);
+        }
+        let _ = writeln!(
+            out,
+            "{} : {}",
+            texts.global_score,
+            (texts.score)(results.global_score)
+        );
+        if let Some(feedback) = &results.global_feedback {
+            let _ = writeln!(out, "{}", feedback);
+        }
+        if let Some(maturity) = &results.maturity {
+            let _ = writeln!(
+                out,
+                "\n{} : {}",
+                texts.maturity_stage,
+                maturity
+                    .stage()
+                    .map_or(texts.no_stage_reached, |stage| stage.name.as_str())
+            );
+            for check in maturity.matched() {
+                let _ = writeln!(out, "  ✓ {}", (texts.criterion)(&check.criterion));
+            }
+            if let Some(next) = maturity.next_stage() {
+                let _ = writeln!(out, "{} :", (texts.next_stage)(&next.name));
+                for check in maturity.missing() {
+                    let _ = writeln!(out, "  ○ {}", (texts.criterion)(&check.criterion));
+                }
+            }
+        }
+
+        if let Some(eligibility) = &results.eligibility {
+            let _ = writeln!(
+                out,
+                "\n{} : {}",
+                texts.eligibility_title,
+                (texts.verdict)(eligibility.verdict)
+            );
+            for check in eligibility.required.iter().chain(&eligibility.conditions) {
+                let _ = writeln!(
+                    out,
+                    "  {} {}",
+                    if check.met { "✓" } else { "○" },
+                    (texts.criterion)(&check.criterion)
+                );
+            }
+        }
+
+        let _ = writeln!(out, "\n{}", texts.detailed_analysis);
+        for thematic in &results.thematics {
+            let _ = writeln!(
+                out,
+                "- {}{} : {} ({})",
+                thematic
+                    .icon
+                    .as_ref()
+                    .map(|icon| format!("{} ", icon))
+                    .unwrap_or_default(),
+                thematic.name,
+                (texts.score)(thematic.score),
+                (texts.completion)(&thematic.stats)
+            );
+            for dimension in &thematic.dimensions {
+                let _ = writeln!(
+                    out,
+                    "  · {} : {}",
+                    dimension.name,
+                    (texts.score)(dimension.score)
+                );
+            }
+            if let Some(feedback) = &thematic.feedback {
+                let _ = writeln!(out, "  {}", feedback);
+            }
+        }
+
+        if !results.action_plan.is_empty() {
+            let _ = writeln!(out, "\n{}", texts.action_plan);
+            for item in &results.action_plan {
+                let _ = writeln!(
+                    out,
+                    "- [{}] {}\n  {}",
+                    item.thematic, item.question, item.recommendation.text
+                );
+            }
+        }
+        let _ = writeln!(out);
+        out
+    }
+}
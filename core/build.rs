@@ -0,0 +1,78 @@
+//! Génère la liste des fichiers intégrés au binaire à partir des catalogues
+//! `questions/catalog.yaml` : ajouter une thématique ne demande que du YAML.
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Constante générée et dossier des questions de chaque langue
+const LOCALES: [(&str, &str); 2] = [("EMBEDDED_FR", ""), ("EMBEDDED_EN", "en/")];
+
+fn main() {
+    let questions = Path::new(&env::var("CARGO_MANIFEST_DIR").unwrap()).join("../questions");
+    let questions = questions.canonicalize().unwrap_or(questions);
+    println!("cargo:rerun-if-changed={}", questions.display());
+
+    let mut generated = String::new();
+    for (name, dir) in LOCALES {
+        let catalog_file = format!("{}catalog.yaml", dir);
+        let catalog_path = questions.join(&catalog_file);
+        println!("cargo:rerun-if-changed={}", catalog_path.display());
+
+        // Un catalogue illisible est signalé au chargement, avec sa position
+        let thematics = fs::read_to_string(&catalog_path)
+            .ok()
+            .and_then(|content| serde_yaml::from_str::<serde_yaml::Value>(&content).ok())
+            .map(|catalog| thematic_files(&catalog))
+            .unwrap_or_default();
+
+        writeln!(
+            generated,
+            "const {}: EmbeddedQuestions = EmbeddedQuestions {{\n    catalog: ({:?}, include_str!({:?})),\n    thematics: &[",
+            name,
+            catalog_file,
+            path_str(&catalog_path)
+        )
+        .unwrap();
+        for file in thematics {
+            let path = questions.join(dir).join(&file);
+            if path.is_file() {
+                writeln!(
+                    generated,
+                    "        ({:?}, include_str!({:?})),",
+                    file,
+                    path_str(&path)
+                )
+                .unwrap();
+            }
+        }
+        generated.push_str("    ],\n};\n");
+    }
+
+    let out = PathBuf::from(env::var("OUT_DIR").unwrap()).join("embedded_questions.rs");
+    fs::write(out, generated).unwrap();
+}
+
+/// Fichiers des thématiques référencés par les questionnaires, sans doublon et
+/// dans l'ordre du catalogue
+fn thematic_files(catalog: &serde_yaml::Value) -> Vec<String> {
+    let mut files: Vec<String> = Vec::new();
+    let referenced = catalog["templates"]
+        .as_sequence()
+        .into_iter()
+        .flatten()
+        .filter_map(|template| template["thematics"].as_sequence())
+        .flatten()
+        .filter_map(|file| file.as_str());
+    for file in referenced {
+        if !files.iter().any(|known| known == file) {
+            files.push(file.to_string());
+        }
+    }
+    files
+}
+
+fn path_str(path: &Path) -> String {
+    path.to_str().expect("chemin non UTF-8").to_string()
+}
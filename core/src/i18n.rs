@@ -0,0 +1,212 @@
+use crate::models::{Criterion, ThematicStats, Verdict};
+use serde::{Deserialize, Serialize};
+
+/// Langue de l'interface et du questionnaire
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Locale {
+    #[default]
+    Fr,
+    En,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 2] = [Locale::Fr, Locale::En];
+
+    /// Code ISO 639-1, transmis à `/api/contact` et utilisé dans l'URL (`?lang=en`)
+    pub fn code(&self) -> &'static str {
+        match self {
+            Locale::Fr => "fr",
+            Locale::En => "en",
+        }
+    }
+
+    /// Accepte un code de langue seul ou avec sa région (`en-US`)
+    pub fn from_code(code: &str) -> Option<Self> {
+        let language = code.split(['-', '_']).next()?.to_ascii_lowercase();
+        Self::ALL
+            .into_iter()
+            .find(|locale| locale.code() == language)
+    }
+
+    /// Sous-dossier de `questions/` contenant les fichiers traduits
+    pub fn questions_dir(&self) -> &'static str {
+        match self {
+            Locale::Fr => "",
+            Locale::En => "en/",
+        }
+    }
+
+    pub fn texts(&self) -> &'static Texts {
+        match self {
+            Locale::Fr => &FR,
+            Locale::En => &EN,
+        }
+    }
+}
+
+/// Textes du questionnaire et des résultats dans une langue, communs à
+/// l'application web et à l'outil en ligne de commande. Chaque langue renseigne
+/// tous les champs : un texte manquant est une erreur de compilation.
+pub struct Texts {
+    // Questions
+    pub yes: &'static str,
+    /// Touches de raccourci des réponses Oui et Non, en minuscules
+    pub yes_key: &'static str,
+    pub no_key: &'static str,
+    pub no: &'static str,
+    pub dont_know: &'static str,
+    pub question_progress: fn(usize, usize) -> String,
+
+    // Résultats
+    pub global_score: &'static str,
+    /// Score en pourcentage, « non évalué » pour une thématique sans réponse notée
+    pub score: fn(Option<f64>) -> String,
+    pub detailed_analysis: &'static str,
+    pub maturity_stage: &'static str,
+    pub no_stage_reached: &'static str,
+    pub next_stage: fn(&str) -> String,
+    pub criterion: fn(&Criterion) -> String,
+    pub completion: fn(&ThematicStats) -> String,
+    pub action_plan: &'static str,
+    pub eligibility_title: &'static str,
+    pub verdict: fn(Verdict) -> &'static str,
+}
+
+fn fr_score(score: Option<f64>) -> String {
+    match score {
+        Some(score) => format!("{:.0}%", score),
+        None => "non évalué".to_string(),
+    }
+}
+
+fn en_score(score: Option<f64>) -> String {
+    match score {
+        Some(score) => format!("{:.0}%", score),
+        None => "not assessed".to_string(),
+    }
+}
+
+pub const FR: Texts = Texts {
+    yes: "Oui",
+    yes_key: "o",
+    no_key: "n",
+    no: "Non",
+    dont_know: "Je ne sais pas",
+    question_progress: |position, total| format!("Question {} sur {}", position, total),
+
+    global_score: "Score global",
+    score: fr_score,
+    detailed_analysis: "Analyse détaillée",
+    maturity_stage: "Stade de maturité",
+    no_stage_reached: "Aucun stade atteint",
+    next_stage: |stage| format!("Pour atteindre le stade « {} »", stage),
+    criterion: |criterion| match criterion {
+        Criterion::GlobalScore { min, score } => {
+            format!(
+                "Score global d'au moins {:.0}% (actuel : {})",
+                min,
+                fr_score(*score)
+            )
+        }
+        Criterion::Completion { min, completion } => format!(
+            "Au moins {:.0}% des questions répondues (actuel : {:.0}%)",
+            min, completion
+        ),
+        Criterion::ThematicScore {
+            name, min, score, ..
+        } => format!(
+            "{} : au moins {:.0}% (actuel : {})",
+            name,
+            min,
+            fr_score(*score)
+        ),
+        Criterion::AnsweredYes { text, .. } => format!("« Oui » à : {}", text),
+        Criterion::NotAssessable { reference } => {
+            format!("Non évaluable avec ce questionnaire : {}", reference)
+        }
+    },
+    completion: |stats| {
+        let total = stats.answered + stats.unanswered;
+        let mut label = format!("{}/{} questions répondues", stats.answered, total);
+        if stats.unanswered > 0 {
+            label.push_str(&format!(", {} sans réponse", stats.unanswered));
+        }
+        if stats.skipped > 0 {
+            label.push_str(&format!(" dont {} passées", stats.skipped));
+        }
+        if stats.unknown > 0 {
+            label.push_str(&format!(", {} « Je ne sais pas »", stats.unknown));
+        }
+        label
+    },
+    action_plan: "Plan d'action",
+    eligibility_title: "Éligibilité au programme Start to Scale",
+    verdict: |verdict| match verdict {
+        Verdict::Eligible => "Éligible",
+        Verdict::EligibleWithConditions => "Éligible sous conditions",
+        Verdict::NotYet => "Pas encore éligible",
+    },
+};
+
+pub const EN: Texts = Texts {
+    yes: "Yes",
+    yes_key: "y",
+    no_key: "n",
+    no: "No",
+    dont_know: "I don't know",
+    question_progress: |position, total| format!("Question {} of {}", position, total),
+
+    global_score: "Overall score",
+    score: en_score,
+    detailed_analysis: "Detailed analysis",
+    maturity_stage: "Maturity stage",
+    no_stage_reached: "No stage reached",
+    next_stage: |stage| format!("To reach the “{}” stage", stage),
+    criterion: |criterion| match criterion {
+        Criterion::GlobalScore { min, score } => {
+            format!(
+                "Global score of at least {:.0}% (currently {})",
+                min,
+                en_score(*score)
+            )
+        }
+        Criterion::Completion { min, completion } => format!(
+            "At least {:.0}% of the questions answered (currently {:.0}%)",
+            min, completion
+        ),
+        Criterion::ThematicScore {
+            name, min, score, ..
+        } => format!(
+            "{}: at least {:.0}% (currently {})",
+            name,
+            min,
+            en_score(*score)
+        ),
+        Criterion::AnsweredYes { text, .. } => format!("“Yes” to: {}", text),
+        Criterion::NotAssessable { reference } => {
+            format!("Not assessable with this questionnaire: {}", reference)
+        }
+    },
+    completion: |stats| {
+        let total = stats.answered + stats.unanswered;
+        let mut label = format!("{}/{} questions answered", stats.answered, total);
+        if stats.unanswered > 0 {
+            label.push_str(&format!(", {} unanswered", stats.unanswered));
+        }
+        if stats.skipped > 0 {
+            label.push_str(&format!(" ({} skipped)", stats.skipped));
+        }
+        if stats.unknown > 0 {
+            label.push_str(&format!(", {} “I don't know”", stats.unknown));
+        }
+        label
+    },
+    action_plan: "Action plan",
+    eligibility_title: "Start to Scale programme eligibility",
+    verdict: |verdict| match verdict {
+        Verdict::Eligible => "Eligible",
+        Verdict::EligibleWithConditions => "Eligible with conditions",
+        Verdict::NotYet => "Not yet eligible",
+    },
+};
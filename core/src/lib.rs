@@ -0,0 +1,10 @@
+//! Logique du mini audit Start to Scale, sans dépendance au navigateur :
+//! modèles, chargement et validation des questionnaires, calcul des scores et
+//! encodage des liens de résultats. Utilisée par l'application web et testée
+//! nativement avec `cargo test`.
+
+pub mod i18n;
+pub mod models;
+pub mod scoring;
+pub mod share;
+pub mod survey;
@@ -0,0 +1,1013 @@
+use crate::i18n::Texts;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Question {
+    /// Identifiant stable défini dans le fichier YAML (slug ou UUID)
+    pub id: String,
+    pub text: String,
+    pub description: Option<String>,
+    pub thematic: String,
+    /// Identifiant de la sous-dimension de la thématique, si elle en déclare
+    pub dimension: Option<String>,
+    /// Poids de la question dans le score de sa thématique
+    pub weight: f64,
+    /// Scores personnalisés par réponse, à la place du barème par défaut
+    pub scores: Option<AnswerScores>,
+    /// Type de réponse attendu
+    pub kind: AnswerKind,
+    /// Conditions d'affichage, toutes requises ; vide si toujours affichée
+    pub visible_if: Vec<VisibilityCondition>,
+    /// Prochaine étape proposée lorsque la réponse révèle un point à améliorer
+    pub recommendation: Option<Recommendation>,
+}
+
+/// Recommandation associée à une question, reprise dans le plan d'action
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Recommendation {
+    pub text: String,
+    #[serde(default)]
+    pub resources: Vec<Resource>,
+    #[serde(default)]
+    pub effort: Level,
+    #[serde(default)]
+    pub impact: Level,
+}
+
+impl Recommendation {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.text.trim().is_empty() {
+            return Err("recommandation sans texte".to_string());
+        }
+        if let Some(resource) = self
+            .resources
+            .iter()
+            .find(|r| !r.url.starts_with("https://") && !r.url.starts_with("http://"))
+        {
+            return Err(format!(
+                "lien invalide pour la ressource « {} » : {}",
+                resource.label, resource.url
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Lien vers une ressource utile pour mettre en œuvre une recommandation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Resource {
+    pub label: String,
+    pub url: String,
+}
+
+/// Niveau d'effort ou d'impact d'une recommandation
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Level {
+    Faible,
+    #[default]
+    Moyen,
+    Eleve,
+}
+
+impl Level {
+    /// Valeur utilisée pour prioriser le plan d'action
+    pub fn rank(&self) -> f64 {
+        match self {
+            Level::Faible => 1.0,
+            Level::Moyen => 2.0,
+            Level::Eleve => 3.0,
+        }
+    }
+}
+
+/// Élément du plan d'action : recommandation d'une question à laquelle la
+/// réponse donnée révèle un point à améliorer
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ActionItem {
+    pub question_id: String,
+    pub question: String,
+    pub thematic: String,
+    pub answer_label: String,
+    pub recommendation: Recommendation,
+}
+
+/// Condition d'affichage d'une question, portant sur la réponse à une question
+/// précédente du questionnaire
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct VisibilityCondition {
+    /// Identifiant de la question dont dépend l'affichage
+    pub question: String,
+    /// Réponses qui rendent la question visible, par ex. `oui` ou `!choix 2`
+    pub answers: Vec<Answer>,
+}
+
+impl VisibilityCondition {
+    pub fn is_met(&self, answer: &Answer) -> bool {
+        self.answers.contains(answer)
+    }
+}
+
+/// Type de réponse attendu pour une question, déclaré dans le YAML
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum AnswerKind {
+    /// Oui / Non / Je ne sais pas
+    #[default]
+    OuiNon,
+    /// Échelle de 1 à 5
+    Likert {
+        #[serde(default)]
+        min_label: Option<String>,
+        #[serde(default)]
+        max_label: Option<String>,
+    },
+    /// Un seul choix parmi des options libellées
+    SingleChoice { options: Vec<ChoiceOption> },
+    /// Plusieurs choix possibles, dont les scores s'additionnent (plafonnés à 100)
+    MultipleChoice { options: Vec<ChoiceOption> },
+    /// Valeur numérique convertie en score par paliers
+    Numeric {
+        #[serde(default)]
+        unit: Option<String>,
+        thresholds: Vec<Threshold>,
+    },
+    /// Réponse libre, non prise en compte dans le score
+    Text,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChoiceOption {
+    pub label: String,
+    pub score: f64,
+}
+
+/// Palier d'une question numérique : toute valeur supérieure ou égale à `min`
+/// obtient au moins `score`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Threshold {
+    pub min: f64,
+    pub score: f64,
+}
+
+impl AnswerKind {
+    /// Les réponses libres ne comptent pas dans le score
+    pub fn is_scored(&self) -> bool {
+        !matches!(self, AnswerKind::Text)
+    }
+
+    /// Vérifie que la réponse correspond au type de la question
+    pub fn accepts(&self, answer: &Answer) -> bool {
+        match (self, answer) {
+            (AnswerKind::Text, Answer::Text(_)) => true,
+            (AnswerKind::Text, _) => false,
+            (_, Answer::JeNeSaisPas) => true,
+            (AnswerKind::OuiNon, Answer::Oui | Answer::Non) => true,
+            (AnswerKind::Likert { .. }, Answer::Likert(value)) => (1..=5).contains(value),
+            (AnswerKind::SingleChoice { options }, Answer::Choice(index)) => *index < options.len(),
+            (AnswerKind::MultipleChoice { options }, Answer::Choices(indices)) => {
+                indices.iter().all(|index| *index < options.len())
+            }
+            (AnswerKind::Numeric { .. }, Answer::Number(value)) => value.is_finite(),
+            _ => false,
+        }
+    }
+
+    /// Score (entre 0 et 100) d'une réponse selon le type de la question,
+    /// `None` pour les réponses non notées
+    pub fn score(&self, answer: &Answer) -> Option<f64> {
+        if !self.accepts(answer) {
+            return None;
+        }
+
+        match answer {
+            Answer::Oui | Answer::Non | Answer::JeNeSaisPas => Some(answer.to_score()),
+            Answer::Likert(value) => Some((*value as f64 - 1.0) * 25.0),
+            Answer::Choice(index) => match self {
+                AnswerKind::SingleChoice { options } => Some(options[*index].score),
+                _ => None,
+            },
+            Answer::Choices(indices) => match self {
+                AnswerKind::MultipleChoice { options } => Some(
+                    indices
+                        .iter()
+                        .map(|index| options[*index].score)
+                        .sum::<f64>()
+                        .clamp(0.0, 100.0),
+                ),
+                _ => None,
+            },
+            Answer::Number(value) => match self {
+                AnswerKind::Numeric { thresholds, .. } => Some(
+                    thresholds
+                        .iter()
+                        .filter(|threshold| *value >= threshold.min)
+                        .map(|threshold| threshold.score)
+                        .fold(0.0, f64::max),
+                ),
+                _ => None,
+            },
+            Answer::Text(_) => None,
+        }
+    }
+
+    /// Libellé lisible d'une réponse, pour l'affichage et la note CRM
+    pub fn label(&self, answer: &Answer, texts: &Texts) -> String {
+        match (self, answer) {
+            (_, Answer::Oui) => texts.yes.to_string(),
+            (_, Answer::Non) => texts.no.to_string(),
+            (_, Answer::JeNeSaisPas) => texts.dont_know.to_string(),
+            (_, Answer::Likert(value)) => format!("{}/5", value),
+            (AnswerKind::SingleChoice { options }, Answer::Choice(index)) => options
+                .get(*index)
+                .map(|option| option.label.clone())
+                .unwrap_or_default(),
+            (AnswerKind::MultipleChoice { options }, Answer::Choices(indices)) => indices
+                .iter()
+                .filter_map(|index| options.get(*index))
+                .map(|option| option.label.as_str())
+                .collect::<Vec<_>>()
+                .join(", "),
+            (AnswerKind::Numeric { unit, .. }, Answer::Number(value)) => match unit {
+                Some(unit) => format!("{} {}", value, unit),
+                None => value.to_string(),
+            },
+            (_, Answer::Text(text)) => text.clone(),
+            _ => String::new(),
+        }
+    }
+
+    /// Vérifie la cohérence de la déclaration YAML
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            AnswerKind::SingleChoice { options } | AnswerKind::MultipleChoice { options } => {
+                if options.is_empty() {
+                    return Err("aucune option déclarée".to_string());
+                }
+                if let Some(option) = options
+                    .iter()
+                    .find(|option| !(0.0..=100.0).contains(&option.score))
+                {
+                    return Err(format!("score hors de [0, 100] pour « {} »", option.label));
+                }
+                Ok(())
+            }
+            AnswerKind::Numeric { thresholds, .. } => {
+                if thresholds.is_empty() {
+                    return Err("aucun palier déclaré".to_string());
+                }
+                if thresholds
+                    .iter()
+                    .any(|threshold| !(0.0..=100.0).contains(&threshold.score))
+                {
+                    return Err("score de palier hors de [0, 100]".to_string());
+                }
+                if let Some(threshold) = thresholds
+                    .iter()
+                    .find(|threshold| !threshold.min.is_finite())
+                {
+                    return Err(format!("borne de palier invalide : {}", threshold.min));
+                }
+                Ok(())
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Surcharge du barème d'une question, réponse par réponse
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AnswerScores {
+    pub oui: Option<f64>,
+    pub non: Option<f64>,
+    #[serde(rename = "je-ne-sais-pas")]
+    pub je_ne_sais_pas: Option<f64>,
+}
+
+impl AnswerScores {
+    pub fn get(&self, answer: &Answer) -> Option<f64> {
+        match answer {
+            Answer::Oui => self.oui,
+            Answer::Non => self.non,
+            Answer::JeNeSaisPas => self.je_ne_sais_pas,
+            _ => None,
+        }
+    }
+
+    /// Les scores surchargés restent dans [0, 100], comme ceux du barème
+    pub fn validate(&self) -> Result<(), String> {
+        match [self.oui, self.non, self.je_ne_sais_pas]
+            .into_iter()
+            .flatten()
+            .find(|score| !(0.0..=100.0).contains(score))
+        {
+            Some(score) => Err(format!("score {} hors de [0, 100]", score)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Réponse à une question. Les noms sérialisés sont stables : ils sont partagés
+/// par les conditions d'affichage du YAML, les sauvegardes du navigateur et le
+/// payload de `/api/contact` (`"oui"`, `{"likert": 4}`, `{"choix": 2}`…)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Answer {
+    Oui,
+    Non,
+    JeNeSaisPas,
+    /// Valeur de 1 à 5
+    Likert(u8),
+    /// Index de l'option choisie
+    #[serde(rename = "choix")]
+    Choice(usize),
+    /// Index des options cochées
+    #[serde(rename = "choix-multiples")]
+    Choices(Vec<usize>),
+    #[serde(rename = "nombre")]
+    Number(f64),
+    #[serde(rename = "texte")]
+    Text(String),
+}
+
+impl Answer {
+    /// Score des réponses Oui / Non / Je ne sais pas, 0 pour les autres types
+    /// qui dépendent de la déclaration de la question (voir `AnswerKind::score`)
+    pub fn to_score(&self) -> f64 {
+        match self {
+            Answer::Oui => 100.0,
+            Answer::Non => 0.0,
+            Answer::JeNeSaisPas => 50.0,
+            _ => 0.0,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+
+pub struct QuestionData {
+    pub question: Question,
+    /// Position dans le questionnaire, masquées comprises
+    pub index: usize,
+    pub thematic: String,
+    pub answer: Option<Answer>,
+    /// Libellé lisible de la réponse
+    pub answer_label: Option<String>,
+    /// Question passée sans réponse
+    pub skipped: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct SurveyResults {
+    /// Résultat de chaque thématique, dans l'ordre du questionnaire
+    pub thematics: Vec<ThematicResult>,
+    /// Moyenne des scores des thématiques évaluées, `None` si aucune ne l'est
+    pub global_score: Option<f64>,
+    pub global_feedback: Option<String>,
+    /// Recommandations à mettre en œuvre, par ordre de priorité
+    pub action_plan: Vec<ActionItem>,
+    pub total_answered: usize,
+    pub total_questions: usize,
+    /// Stade de maturité, absent si le catalogue n'en déclare pas
+    #[serde(default)]
+    pub maturity: Option<MaturityResult>,
+    /// Éligibilité au programme, absente si le catalogue ne la définit pas
+    #[serde(default)]
+    pub eligibility: Option<EligibilityResult>,
+}
+
+/// Score, message de retour et détail des réponses d'une thématique
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ThematicResult {
+    /// Identifiant stable de la thématique, commun aux traductions
+    pub id: String,
+    /// Nom affiché
+    pub name: String,
+    /// Rang de la thématique dans le questionnaire, à partir de 0
+    pub order: usize,
+    /// Couleur d'accent (`#rrggbb`)
+    pub color: String,
+    pub icon: Option<String>,
+    /// `None` si aucune réponse notée ne compte : thématique non évaluée, exclue
+    /// du score global et du graphique radar
+    pub score: Option<f64>,
+    /// Message de retour associé au score
+    pub feedback: Option<String>,
+    pub stats: ThematicStats,
+    /// Scores des sous-dimensions, vide si la thématique n'en déclare pas
+    #[serde(default)]
+    pub dimensions: Vec<DimensionResult>,
+}
+
+/// Score et détail des réponses d'une sous-dimension
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct DimensionResult {
+    pub id: String,
+    pub name: String,
+    /// `None` si aucune réponse notée ne compte
+    pub score: Option<f64>,
+    pub stats: ThematicStats,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ThematicStats {
+    /// Questions ayant reçu une réponse (y compris « Je ne sais pas » et texte libre)
+    pub answered: usize,
+    /// Questions laissées sans réponse
+    pub unanswered: usize,
+    /// Parmi les questions sans réponse, celles passées avec « Suivant »
+    pub skipped: usize,
+    /// Réponses « Je ne sais pas »
+    pub unknown: usize,
+    /// Part des questions répondues, entre 0 et 1
+    pub completion: f64,
+}
+
+/// Thématique du questionnaire et l'intervalle d'index de ses questions
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThematicSection {
+    pub name: String,
+    pub questions: std::ops::Range<usize>,
+}
+
+/// Avancement d'une thématique, sur ses seules questions visibles
+#[derive(Debug, Clone, PartialEq)]
+pub struct ThematicProgress {
+    pub section: ThematicSection,
+    pub answered: usize,
+    pub total: usize,
+    /// Première question visible de la thématique, cible de la navigation
+    pub first_visible: Option<usize>,
+}
+
+/// Traitement des questions sans réponse lors du calcul des résultats
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum UnansweredPolicy {
+    /// Une question sans réponse compte comme un « Non »
+    #[default]
+    CountAsZero,
+    /// Les questions sans réponse sont ignorées dans la moyenne
+    Exclude,
+    /// Les résultats ne sont pas calculés tant qu'il reste des questions sans réponse
+    Block,
+}
+
+/// Résultats refusés par `UnansweredPolicy::Block`
+#[derive(Debug, Clone, PartialEq)]
+pub struct IncompleteSurvey {
+    /// Index des questions restant sans réponse, dans l'ordre du questionnaire
+    pub unanswered: Vec<usize>,
+}
+
+/// Erreur de chargement d'un fichier du questionnaire
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuestionBankError {
+    pub file: String,
+    /// Ligne (à partir de 1), lorsqu'elle est connue
+    pub line: Option<usize>,
+    /// Colonne (à partir de 1), lorsqu'elle est connue
+    pub column: Option<usize>,
+    pub reason: String,
+}
+
+impl QuestionBankError {
+    pub fn from_yaml(file: &str, err: &serde_yaml::Error) -> Self {
+        let location = err.location();
+        Self {
+            file: file.to_string(),
+            line: location.as_ref().map(|l| l.line()),
+            column: location.as_ref().map(|l| l.column()),
+            reason: err.to_string(),
+        }
+    }
+
+    /// Erreur de validation localisée sur la première ligne déclarant `key`
+    pub fn at_key(file: &str, content: &str, key: &str, reason: String) -> Self {
+        let prefix = format!("{}:", key);
+        let line = content
+            .lines()
+            .position(|line| line.starts_with(&prefix))
+            .map(|index| index + 1);
+
+        Self {
+            file: file.to_string(),
+            line,
+            column: line.map(|_| 1),
+            reason,
+        }
+    }
+
+    /// Erreur de validation de la question n° `index` (à partir de 0) de la liste
+    /// `questions`, localisée sur la ligne de son `id`, à défaut sur le début de
+    /// l'élément
+    pub fn at_question(file: &str, content: &str, index: usize, reason: String) -> Self {
+        let lines: Vec<&str> = content.lines().collect();
+        let position = lines
+            .iter()
+            .position(|line| line.starts_with("questions:"))
+            .and_then(|start| {
+                // Début de chaque élément de la liste, au niveau d'indentation du premier
+                let mut items = Vec::new();
+                let mut indent = None;
+                for (number, line) in lines.iter().enumerate().skip(start + 1) {
+                    let trimmed = line.trim_start();
+                    if trimmed.is_empty() || trimmed.starts_with('#') {
+                        continue;
+                    }
+                    let column = line.len() - trimmed.len();
+                    if column == 0 {
+                        break;
+                    }
+                    if trimmed.starts_with('-') && *indent.get_or_insert(column) == column {
+                        items.push(number);
+                    }
+                }
+                let indent = indent?;
+                let first = *items.get(index)?;
+                let end = items.get(index + 1).copied().unwrap_or(lines.len());
+                let id_line = (first..end).find_map(|number| {
+                    let column = lines[number].find("id:")?;
+                    let is_key = column == indent + 2
+                        && lines[number][..column]
+                            .trim_start_matches([' ', '-'])
+                            .is_empty();
+                    is_key.then_some((number + 1, column + 1))
+                });
+                Some(id_line.unwrap_or((first + 1, indent + 1)))
+            });
+
+        Self {
+            file: file.to_string(),
+            line: position.map(|(line, _)| line),
+            column: position.map(|(_, column)| column),
+            reason,
+        }
+    }
+}
+
+impl std::fmt::Display for QuestionBankError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.file)?;
+        if let Some(line) = self.line {
+            write!(f, ":{}", line)?;
+            if let Some(column) = self.column {
+                write!(f, ":{}", column)?;
+            }
+        }
+        write!(f, " : {}", self.reason)
+    }
+}
+
+/// Thématique d'un questionnaire, déclarée par un fichier YAML
+#[derive(Debug, Clone, PartialEq)]
+pub struct Thematic {
+    /// Identifiant stable, identique dans chaque langue
+    pub id: String,
+    pub name: String,
+    /// Couleur d'accent de la thématique (`#rrggbb`)
+    pub color: String,
+    pub icon: Option<String>,
+    pub intro: Option<String>,
+    /// Sous-dimensions, dans l'ordre de déclaration
+    pub dimensions: Vec<Dimension>,
+    /// Messages associés au score de la thématique
+    pub feedback: Vec<FeedbackBand>,
+}
+
+/// Niveau intermédiaire d'une thématique (par ex. Acquisition pour le Go-to-Market)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Dimension {
+    /// Identifiant référencé par les questions (`dimension`)
+    pub id: String,
+    pub name: String,
+}
+
+/// Identifiant stable : minuscules, chiffres et tirets
+fn is_slug(id: &str) -> bool {
+    !id.is_empty()
+        && id
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+/// Couleurs attribuées, selon leur rang, aux thématiques qui n'en déclarent pas
+pub const DEFAULT_THEMATIC_COLORS: [&str; 6] = [
+    "#d32f2f", "#1976d2", "#388e3c", "#f57c00", "#7b1fa2", "#00897b",
+];
+
+impl Thematic {
+    /// Vérifie le format des identifiants de la thématique et de ses
+    /// sous-dimensions (minuscules, chiffres et tirets) et de la couleur (`#rgb`
+    /// ou `#rrggbb`) ; l'erreur indique la clé YAML en cause
+    pub fn validate(&self) -> Result<(), (&'static str, String)> {
+        if !is_slug(&self.id) {
+            return Err((
+                "id",
+                format!(
+                    "identifiant de thématique invalide : « {} » (minuscules, chiffres et tirets)",
+                    self.id
+                ),
+            ));
+        }
+        let hex = self.color.strip_prefix('#').unwrap_or_default();
+        if !matches!(hex.len(), 3 | 6) || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err((
+                "color",
+                format!(
+                    "couleur invalide pour la thématique {} : « {} » (#rrggbb attendu)",
+                    self.id, self.color
+                ),
+            ));
+        }
+        for (index, dimension) in self.dimensions.iter().enumerate() {
+            if !is_slug(&dimension.id) {
+                return Err((
+                    "dimensions",
+                    format!(
+                        "identifiant de sous-dimension invalide : « {} » (minuscules, chiffres et tirets)",
+                        dimension.id
+                    ),
+                ));
+            }
+            if self.dimensions[..index]
+                .iter()
+                .any(|other| other.id == dimension.id)
+            {
+                return Err((
+                    "dimensions",
+                    format!("sous-dimension déclarée deux fois : {}", dimension.id),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Catalogue des questionnaires proposés (`questions/catalog.yaml`)
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct QuestionCatalog {
+    pub templates: Vec<SurveyTemplate>,
+    /// Messages associés au score global
+    #[serde(default)]
+    pub feedback: Vec<FeedbackBand>,
+    /// Stades de maturité, du moins au plus avancé
+    #[serde(default)]
+    pub stages: Vec<MaturityStage>,
+    /// Critères d'éligibilité au programme
+    #[serde(default)]
+    pub eligibility: Option<EligibilityRules>,
+}
+
+impl QuestionCatalog {
+    /// Fichiers des thématiques référencés par les questionnaires, sans doublon
+    /// et dans l'ordre du catalogue
+    pub fn thematic_files(&self) -> Vec<&str> {
+        let mut files: Vec<&str> = Vec::new();
+        for file in self
+            .templates
+            .iter()
+            .flat_map(|template| &template.thematics)
+        {
+            if !files.contains(&file.as_str()) {
+                files.push(file);
+            }
+        }
+        files
+    }
+}
+
+/// Message de retour affiché pour les scores inférieurs ou égaux à `max`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FeedbackBand {
+    pub max: f64,
+    pub message: String,
+}
+
+impl FeedbackBand {
+    /// Message de la première tranche contenant le score
+    pub fn message_for(bands: &[FeedbackBand], score: f64) -> Option<&str> {
+        bands
+            .iter()
+            .find(|band| score <= band.max)
+            .map(|band| band.message.as_str())
+    }
+
+    /// Vérifie que les tranches, par bornes croissantes, couvrent tous les scores
+    /// de 0 à 100 avec un message non vide
+    pub fn validate(bands: &[FeedbackBand]) -> Result<(), String> {
+        let Some(last) = bands.last() else {
+            return Err("aucun message de retour (feedback) déclaré".to_string());
+        };
+        if let Some(pair) = bands.windows(2).find(|pair| pair[1].max <= pair[0].max) {
+            return Err(format!(
+                "les tranches de feedback doivent avoir des bornes croissantes ({} puis {})",
+                pair[0].max, pair[1].max
+            ));
+        }
+        if bands[0].max < 0.0 {
+            return Err(format!("borne de feedback négative ({})", bands[0].max));
+        }
+        if last.max < 100.0 {
+            return Err(format!(
+                "les scores au-delà de {} n'ont pas de message de feedback",
+                last.max
+            ));
+        }
+        if let Some(band) = bands.iter().find(|band| band.message.trim().is_empty()) {
+            return Err(format!(
+                "message de feedback vide pour la tranche jusqu'à {}",
+                band.max
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Règles déclaratives évaluées sur les résultats, communes aux stades de
+/// maturité et à l'éligibilité
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Requirements {
+    /// Score global minimal
+    #[serde(default)]
+    pub min_global_score: Option<f64>,
+    /// Part minimale (en %) des questions ayant reçu une réponse, pour que les
+    /// questions passées ne suffisent pas à remplir les autres critères
+    #[serde(default)]
+    pub min_completion: Option<f64>,
+    /// Scores minimaux par identifiant de thématique
+    #[serde(default)]
+    pub min_scores: Vec<ScoreRequirement>,
+    /// Questions auxquelles il faut avoir répondu « Oui »
+    #[serde(default)]
+    pub required_yes: Vec<String>,
+}
+
+impl Requirements {
+    /// Vérifie que les seuils sont compris entre 0 et 100
+    pub fn validate(&self) -> Result<(), String> {
+        let thresholds = self
+            .min_global_score
+            .iter()
+            .chain(&self.min_completion)
+            .chain(self.min_scores.iter().map(|requirement| &requirement.min));
+        for min in thresholds {
+            if !(0.0..=100.0).contains(min) {
+                return Err(format!("seuil hors de l'intervalle 0-100 : {}", min));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Stade de maturité (Idéation, Traction…) et les règles pour l'atteindre.
+/// Un stade sans critère est toujours atteint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MaturityStage {
+    pub id: String,
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(flatten)]
+    pub requirements: Requirements,
+}
+
+/// Score minimal exigé pour une thématique
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScoreRequirement {
+    pub thematic: String,
+    pub min: f64,
+}
+
+impl MaturityStage {
+    /// Vérifie les identifiants, les noms et les seuils des stades
+    pub fn validate(stages: &[MaturityStage]) -> Result<(), String> {
+        for (index, stage) in stages.iter().enumerate() {
+            if !is_slug(&stage.id) {
+                return Err(format!(
+                    "identifiant de stade invalide : « {} » (minuscules, chiffres et tirets)",
+                    stage.id
+                ));
+            }
+            if stages[..index].iter().any(|other| other.id == stage.id) {
+                return Err(format!("stade déclaré deux fois : {}", stage.id));
+            }
+            if stage.name.trim().is_empty() {
+                return Err(format!("nom vide pour le stade {}", stage.id));
+            }
+            stage
+                .requirements
+                .validate()
+                .map_err(|reason| format!("stade {} : {}", stage.id, reason))?;
+        }
+        Ok(())
+    }
+}
+
+/// Règle d'un stade de maturité, évaluée sur les résultats
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum Criterion {
+    GlobalScore {
+        min: f64,
+        /// `None` si aucune thématique n'est évaluée
+        score: Option<f64>,
+    },
+    Completion {
+        min: f64,
+        /// Part des questions ayant reçu une réponse, en %
+        completion: f64,
+    },
+    ThematicScore {
+        /// Identifiant de la thématique
+        thematic: String,
+        name: String,
+        min: f64,
+        /// `None` si la thématique n'est pas évaluée
+        score: Option<f64>,
+    },
+    AnsweredYes {
+        /// Identifiant de la question
+        question: String,
+        text: String,
+    },
+    /// Thématique ou question absente du questionnaire : le critère ne peut
+    /// pas être rempli
+    NotAssessable {
+        /// Identifiant de la thématique ou de la question
+        reference: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CriterionCheck {
+    pub criterion: Criterion,
+    pub met: bool,
+}
+
+/// Stade de maturité et ses critères évalués
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StageEvaluation {
+    pub id: String,
+    pub name: String,
+    pub description: Option<String>,
+    pub criteria: Vec<CriterionCheck>,
+}
+
+impl StageEvaluation {
+    pub fn is_met(&self) -> bool {
+        self.criteria.iter().all(|check| check.met)
+    }
+}
+
+/// Classement d'une startup parmi les stades de maturité du catalogue
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MaturityResult {
+    /// Tous les stades, du moins au plus avancé
+    pub stages: Vec<StageEvaluation>,
+    /// Index du stade atteint : le plus avancé dont les critères, et ceux de
+    /// tous les stades précédents, sont remplis
+    pub current: Option<usize>,
+}
+
+impl MaturityResult {
+    pub fn from_stages(stages: Vec<StageEvaluation>) -> Self {
+        let current = stages
+            .iter()
+            .take_while(|stage| stage.is_met())
+            .count()
+            .checked_sub(1);
+        Self { stages, current }
+    }
+
+    pub fn stage(&self) -> Option<&StageEvaluation> {
+        self.current.map(|index| &self.stages[index])
+    }
+
+    pub fn next_stage(&self) -> Option<&StageEvaluation> {
+        self.stages.get(self.current.map_or(0, |index| index + 1))
+    }
+
+    /// Critères remplis du stade atteint
+    pub fn matched(&self) -> &[CriterionCheck] {
+        self.stage().map_or(&[], |stage| &stage.criteria)
+    }
+
+    /// Critères du stade suivant qui restent à remplir
+    pub fn missing(&self) -> Vec<&CriterionCheck> {
+        self.next_stage()
+            .map(|stage| stage.criteria.iter().filter(|check| !check.met).collect())
+            .unwrap_or_default()
+    }
+}
+
+/// Critères d'éligibilité au programme : les critères indispensables
+/// (`required`) conditionnent la candidature, les conditions (`conditions`)
+/// restent à remplir pendant l'accompagnement
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EligibilityRules {
+    #[serde(default)]
+    pub required: Requirements,
+    #[serde(default)]
+    pub conditions: Requirements,
+}
+
+/// Verdict d'éligibilité au programme
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Verdict {
+    /// Critères indispensables et conditions remplis
+    Eligible,
+    /// Critères indispensables remplis, conditions manquantes
+    EligibleWithConditions,
+    /// Critère indispensable manquant
+    NotYet,
+}
+
+/// Verdict d'éligibilité et critères évalués
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EligibilityResult {
+    pub verdict: Verdict,
+    pub required: Vec<CriterionCheck>,
+    pub conditions: Vec<CriterionCheck>,
+}
+
+impl EligibilityResult {
+    pub fn new(required: Vec<CriterionCheck>, conditions: Vec<CriterionCheck>) -> Self {
+        let all_met = |checks: &[CriterionCheck]| checks.iter().all(|check| check.met);
+        let verdict = if !all_met(&required) {
+            Verdict::NotYet
+        } else if !all_met(&conditions) {
+            Verdict::EligibleWithConditions
+        } else {
+            Verdict::Eligible
+        };
+        Self {
+            verdict,
+            required,
+            conditions,
+        }
+    }
+}
+
+/// Modèle d'audit : métadonnées et liste des thématiques qui le composent
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SurveyTemplate {
+    pub id: String,
+    pub title: String,
+    pub description: String,
+    pub version: String,
+    /// Fichiers des thématiques, relatifs au catalogue, dans l'ordre d'affichage
+    pub thematics: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ThematicQuestions {
+    /// Identifiant stable, par défaut le nom du fichier sans extension
+    #[serde(default)]
+    pub id: Option<String>,
+    /// Rang d'affichage ; les thématiques sans rang suivent, dans l'ordre du catalogue
+    #[serde(default)]
+    pub order: Option<u32>,
+    /// Couleur d'accent (`#rrggbb`), par défaut prise dans `DEFAULT_THEMATIC_COLORS`
+    #[serde(default)]
+    pub color: Option<String>,
+    /// Icône affichée devant le nom (emoji)
+    #[serde(default)]
+    pub icon: Option<String>,
+    /// Courte introduction affichée sous le titre de la thématique
+    #[serde(default)]
+    pub intro: Option<String>,
+    pub thematic: String,
+    /// Sous-dimensions optionnelles ; chaque question en désigne alors une
+    #[serde(default)]
+    pub dimensions: Vec<Dimension>,
+    pub questions: Vec<QuestionYaml>,
+    /// Messages associés au score de la thématique
+    #[serde(default)]
+    pub feedback: Vec<FeedbackBand>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct QuestionYaml {
+    pub id: String,
+    pub text: String,
+    pub description: Option<String>,
+    #[serde(default = "default_weight")]
+    pub weight: f64,
+    #[serde(default)]
+    pub scores: Option<AnswerScores>,
+    #[serde(default)]
+    pub answer: AnswerKind,
+    #[serde(default)]
+    pub visible_if: Vec<VisibilityCondition>,
+    #[serde(default)]
+    pub recommendation: Option<Recommendation>,
+    /// Sous-dimension de la thématique à laquelle la question se rattache
+    #[serde(default)]
+    pub dimension: Option<String>,
+}
+
+fn default_weight() -> f64 {
+    1.0
+}
@@ -0,0 +1,93 @@
+use crate::models::{Answer, Question};
+
+/// Stratégie de calcul des scores du questionnaire.
+///
+/// Permet de faire évoluer le barème sans toucher à `Survey` : le score d'une
+/// réponse et l'agrégation par thématique sont délégués à l'implémentation.
+pub trait ScoringStrategy {
+    /// Score (entre 0 et 100) d'une réponse donnée à une question, `None` si la
+    /// réponse ne compte pas dans le score (texte libre)
+    fn answer_score(&self, question: &Question, answer: &Answer) -> Option<f64>;
+
+    /// Score d'une thématique à partir des couples (poids, score) de ses questions,
+    /// `None` si aucune réponse notée ne compte : la thématique n'est pas évaluée
+    fn thematic_score(&self, weighted_scores: &[(f64, f64)]) -> Option<f64>;
+}
+
+/// Barème historique : Oui = 100, Non = 0, Je ne sais pas = 50, avec moyenne
+/// pondérée par le `weight` des questions et surcharges éventuelles du YAML.
+/// Les autres types de réponse sont notés selon leur déclaration (`AnswerKind`).
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DefaultScoring;
+
+impl ScoringStrategy for DefaultScoring {
+    fn answer_score(&self, question: &Question, answer: &Answer) -> Option<f64> {
+        question
+            .scores
+            .as_ref()
+            .and_then(|scores| scores.get(answer))
+            .or_else(|| question.kind.score(answer))
+    }
+
+    fn thematic_score(&self, weighted_scores: &[(f64, f64)]) -> Option<f64> {
+        let total_weight: f64 = weighted_scores.iter().map(|(weight, _)| weight).sum();
+        if total_weight <= 0.0 {
+            return None;
+        }
+
+        Some(
+            weighted_scores
+                .iter()
+                .map(|(weight, score)| weight * score)
+                .sum::<f64>()
+                / total_weight,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn empty_thematic_is_not_evaluated() {
+        assert_eq!(DefaultScoring.thematic_score(&[]), None);
+        assert_eq!(DefaultScoring.thematic_score(&[(0.0, 100.0)]), None);
+    }
+
+    #[test]
+    fn thematic_score_is_a_weighted_average() {
+        assert_eq!(
+            DefaultScoring.thematic_score(&[(1.0, 100.0), (3.0, 0.0)]),
+            Some(25.0)
+        );
+        assert_eq!(
+            DefaultScoring.thematic_score(&[(2.0, 50.0), (2.0, 50.0)]),
+            Some(50.0)
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn thematic_score_stays_within_its_answers(
+            weighted_scores in prop::collection::vec((0.0f64..10.0, 0.0f64..=100.0), 0..20)
+        ) {
+            let score = DefaultScoring.thematic_score(&weighted_scores);
+            prop_assert_eq!(score.is_some(), weighted_scores.iter().any(|(weight, _)| *weight > 0.0));
+            let score = score.unwrap_or_default();
+            prop_assert!((0.0..=100.0).contains(&score));
+
+            let counted: Vec<f64> = weighted_scores
+                .iter()
+                .filter(|(weight, _)| *weight > 0.0)
+                .map(|(_, score)| *score)
+                .collect();
+            if !counted.is_empty() {
+                let min = counted.iter().cloned().fold(f64::INFINITY, f64::min);
+                let max = counted.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+                prop_assert!(score >= min - 1e-9 && score <= max + 1e-9);
+            }
+        }
+    }
+}
@@ -0,0 +1,473 @@
+use crate::models::{Answer, Question};
+use crate::survey::Survey;
+use std::collections::HashMap;
+
+/// Préfixe du fragment d'URL d'un lien de résultats :
+/// `#resultats/<questionnaire>/<version>/<réponses encodées>`
+const FRAGMENT_PREFIX: &str = "#resultats/";
+
+/// Version de l'encodage des réponses, incrémentée à chaque changement de format
+const ENCODING_VERSION: u8 = 1;
+
+const TAG_NONE: u8 = 0;
+const TAG_OUI: u8 = 1;
+const TAG_NON: u8 = 2;
+const TAG_JE_NE_SAIS_PAS: u8 = 3;
+const TAG_LIKERT: u8 = 4;
+const TAG_CHOICE: u8 = 5;
+const TAG_CHOICES: u8 = 6;
+const TAG_NUMBER: u8 = 7;
+
+const BASE64URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Lien de résultats décodé, en attente d'un questionnaire correspondant
+#[derive(Debug, Clone, PartialEq)]
+pub struct SharedAnswers {
+    pub template_id: String,
+    pub template_version: String,
+    pub payload: String,
+}
+
+impl SharedAnswers {
+    /// Lit le fragment d'URL (`window.location.hash`), `None` s'il ne s'agit pas
+    /// d'un lien de résultats
+    pub fn from_fragment(fragment: &str) -> Option<Self> {
+        let mut parts = fragment.strip_prefix(FRAGMENT_PREFIX)?.splitn(3, '/');
+        Some(Self {
+            template_id: parts.next()?.to_string(),
+            template_version: parts.next()?.to_string(),
+            payload: parts.next()?.to_string(),
+        })
+    }
+
+    /// Réponses du lien, décodées selon les questions du questionnaire
+    pub fn decode(&self, survey: &Survey) -> Result<HashMap<String, Answer>, String> {
+        let bytes = base64url_decode(&self.payload).ok_or("lien de résultats illisible")?;
+        let mut reader = Reader {
+            bytes: &bytes,
+            position: 0,
+        };
+
+        if reader.byte()? != ENCODING_VERSION {
+            return Err("format de lien de résultats inconnu".to_string());
+        }
+        let fingerprint = u32::from_be_bytes([
+            reader.byte()?,
+            reader.byte()?,
+            reader.byte()?,
+            reader.byte()?,
+        ]);
+        if fingerprint != questions_fingerprint(survey.questions()) {
+            return Err("le lien a été créé pour d'autres questions".to_string());
+        }
+
+        let mut answers = HashMap::new();
+        for question in survey.questions() {
+            let answer = match reader.byte()? {
+                TAG_NONE => continue,
+                TAG_OUI => Answer::Oui,
+                TAG_NON => Answer::Non,
+                TAG_JE_NE_SAIS_PAS => Answer::JeNeSaisPas,
+                TAG_LIKERT => Answer::Likert(reader.byte()?),
+                TAG_CHOICE => Answer::Choice(reader.varint()?),
+                TAG_CHOICES => {
+                    let count = reader.varint()?;
+                    let mut choices = Vec::new();
+                    for _ in 0..count {
+                        choices.push(reader.varint()?);
+                    }
+                    Answer::Choices(choices)
+                }
+                TAG_NUMBER => {
+                    let mut value = [0u8; 8];
+                    for byte in value.iter_mut() {
+                        *byte = reader.byte()?;
+                    }
+                    Answer::Number(f64::from_be_bytes(value))
+                }
+                tag => return Err(format!("réponse inconnue ({})", tag)),
+            };
+            if !question.kind.accepts(&answer) {
+                return Err(format!("réponse invalide pour la question {}", question.id));
+            }
+            answers.insert(question.id.clone(), answer);
+        }
+
+        if reader.position != bytes.len() {
+            return Err("lien de résultats trop long".to_string());
+        }
+        Ok(answers)
+    }
+}
+
+/// Fragment d'URL encodant les réponses du questionnaire, `None` pour un
+/// questionnaire hors catalogue. Les réponses en texte libre, non notées, ne
+/// sont pas incluses dans le lien.
+pub fn encode_fragment(survey: &Survey) -> Option<String> {
+    let template = survey.template()?;
+    let answers = survey.answers();
+
+    let mut bytes = vec![ENCODING_VERSION];
+    bytes.extend_from_slice(&questions_fingerprint(survey.questions()).to_be_bytes());
+    for question in survey.questions() {
+        match answers.get(&question.id) {
+            None | Some(Answer::Text(_)) => bytes.push(TAG_NONE),
+            Some(Answer::Oui) => bytes.push(TAG_OUI),
+            Some(Answer::Non) => bytes.push(TAG_NON),
+            Some(Answer::JeNeSaisPas) => bytes.push(TAG_JE_NE_SAIS_PAS),
+            Some(Answer::Likert(value)) => bytes.extend_from_slice(&[TAG_LIKERT, *value]),
+            Some(Answer::Choice(index)) => {
+                bytes.push(TAG_CHOICE);
+                push_varint(&mut bytes, *index);
+            }
+            Some(Answer::Choices(indices)) => {
+                bytes.push(TAG_CHOICES);
+                push_varint(&mut bytes, indices.len());
+                for index in indices {
+                    push_varint(&mut bytes, *index);
+                }
+            }
+            Some(Answer::Number(value)) => {
+                bytes.push(TAG_NUMBER);
+                bytes.extend_from_slice(&value.to_be_bytes());
+            }
+        }
+    }
+
+    Some(format!(
+        "{}{}/{}/{}",
+        FRAGMENT_PREFIX,
+        template.id,
+        template.version,
+        base64url_encode(&bytes)
+    ))
+}
+
+/// Empreinte (FNV-1a) des identifiants des questions, dans leur ordre : un lien
+/// n'est relu que si le questionnaire n'a pas changé depuis sa création
+fn questions_fingerprint(questions: &[Question]) -> u32 {
+    let mut hash: u32 = 0x811c_9dc5;
+    for question in questions {
+        for byte in question.id.bytes().chain(std::iter::once(b'\n')) {
+            hash ^= byte as u32;
+            hash = hash.wrapping_mul(0x0100_0193);
+        }
+    }
+    hash
+}
+
+fn push_varint(bytes: &mut Vec<u8>, mut value: usize) {
+    while value >= 0x80 {
+        bytes.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    bytes.push(value as u8);
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl Reader<'_> {
+    fn byte(&mut self) -> Result<u8, String> {
+        let byte = *self
+            .bytes
+            .get(self.position)
+            .ok_or("lien de résultats incomplet")?;
+        self.position += 1;
+        Ok(byte)
+    }
+
+    fn varint(&mut self) -> Result<usize, String> {
+        let mut value = 0usize;
+        for shift in (0..usize::BITS).step_by(7) {
+            let byte = self.byte()?;
+            value |= ((byte & 0x7f) as usize) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err("lien de résultats illisible".to_string())
+    }
+}
+
+/// Base64 « URL-safe » sans remplissage (RFC 4648, section 5)
+fn base64url_encode(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity((bytes.len() + 2) / 3 * 4);
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |group, (i, byte)| {
+            group | (*byte as u32) << (16 - 8 * i)
+        });
+        for i in 0..=chunk.len() {
+            encoded.push(BASE64URL[(group >> (18 - 6 * i) & 0x3f) as usize] as char);
+        }
+    }
+    encoded
+}
+
+fn base64url_decode(encoded: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(encoded.len() * 3 / 4);
+    for chunk in encoded.as_bytes().chunks(4) {
+        if chunk.len() == 1 {
+            return None;
+        }
+        let mut group = 0u32;
+        for (i, c) in chunk.iter().enumerate() {
+            let value = BASE64URL.iter().position(|b| b == c)? as u32;
+            group |= value << (18 - 6 * i);
+        }
+        for i in 0..chunk.len() - 1 {
+            bytes.push((group >> (16 - 8 * i)) as u8);
+        }
+    }
+    Some(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::Locale;
+    use crate::scoring::DefaultScoring;
+    use crate::survey::QuestionBundle;
+
+    const CATALOG: &str = r#"templates:
+  - id: "t"
+    title: "T"
+    description: "D"
+    version: "1.0"
+    thematics:
+      - a.yaml
+feedback:
+  - max: 100
+    message: "M"
+"#;
+
+    /// Thématique couvrant chaque type de réponse ; `options` fixe le nombre
+    /// d'options des questions à choix
+    fn thematic(ids: [&str; 7], options: usize) -> String {
+        let options: String = (0..options)
+            .map(|i| format!("\n        - label: \"O{}\"\n          score: {}", i, i * 10))
+            .collect();
+        format!(
+            r#"thematic: "A"
+questions:
+  - id: "{}"
+    text: "Oui / Non"
+  - id: "{}"
+    text: "Likert"
+    answer:
+      type: likert
+  - id: "{}"
+    text: "Choix"
+    answer:
+      type: single-choice
+      options:{}
+  - id: "{}"
+    text: "Choix multiples"
+    answer:
+      type: multiple-choice
+      options:{}
+  - id: "{}"
+    text: "Nombre"
+    answer:
+      type: numeric
+      thresholds:
+        - min: 0
+          score: 0
+  - id: "{}"
+    text: "Texte"
+    answer:
+      type: text
+  - id: "{}"
+    text: "Non répondue"
+feedback:
+  - max: 100
+    message: "M"
+"#,
+            ids[0], ids[1], ids[2], options, ids[3], options, ids[4], ids[5], ids[6]
+        )
+    }
+
+    const IDS: [&str; 7] = ["q1", "q2", "q3", "q4", "q5", "q6", "q7"];
+
+    fn survey_with(thematic: String) -> Survey {
+        let bundle = QuestionBundle::try_from_catalog(
+            Locale::Fr,
+            "catalog.yaml",
+            CATALOG,
+            vec![("a.yaml".to_string(), thematic)],
+        )
+        .unwrap();
+        Survey::try_from_template(&bundle, "t", DefaultScoring).unwrap()
+    }
+
+    fn survey() -> Survey {
+        survey_with(thematic(IDS, 4))
+    }
+
+    /// Lien de résultats du questionnaire, sous forme décodée
+    fn shared(survey: &Survey) -> SharedAnswers {
+        SharedAnswers::from_fragment(&encode_fragment(survey).unwrap()).unwrap()
+    }
+
+    /// Même lien dont les octets encodés sont modifiés par `edit`
+    fn tampered(shared: &SharedAnswers, edit: impl FnOnce(&mut Vec<u8>)) -> SharedAnswers {
+        let mut bytes = base64url_decode(&shared.payload).unwrap();
+        edit(&mut bytes);
+        SharedAnswers {
+            payload: base64url_encode(&bytes),
+            ..shared.clone()
+        }
+    }
+
+    #[test]
+    fn base64url_round_trips_every_padding_length() {
+        assert_eq!(base64url_encode(b"f"), "Zg");
+        assert_eq!(base64url_encode(b"fo"), "Zm8");
+        assert_eq!(base64url_encode(b"foo"), "Zm9v");
+        assert_eq!(base64url_encode(&[0xfb, 0xff]), "-_8");
+
+        for len in 0..=9 {
+            let bytes: Vec<u8> = (0..len).map(|i| (i * 37 + 200) as u8).collect();
+            assert_eq!(base64url_decode(&base64url_encode(&bytes)), Some(bytes));
+        }
+    }
+
+    #[test]
+    fn base64url_rejects_invalid_input() {
+        assert_eq!(base64url_decode("Zm9vY"), None);
+        assert_eq!(base64url_decode("Zm+v"), None);
+        assert_eq!(base64url_decode("Zm9="), None);
+    }
+
+    #[test]
+    fn varint_round_trips() {
+        for value in [0, 1, 127, 128, 300, 16_384, usize::MAX] {
+            let mut bytes = Vec::new();
+            push_varint(&mut bytes, value);
+            let mut reader = Reader {
+                bytes: &bytes,
+                position: 0,
+            };
+            assert_eq!(reader.varint(), Ok(value));
+            assert_eq!(reader.position, bytes.len());
+        }
+        assert_eq!(
+            Reader {
+                bytes: &[0x80],
+                position: 0
+            }
+            .varint(),
+            Err("lien de résultats incomplet".to_string())
+        );
+    }
+
+    #[test]
+    fn every_answer_kind_round_trips() {
+        let survey = survey();
+        let answers = [
+            Answer::Oui,
+            Answer::Likert(4),
+            Answer::Choice(3),
+            Answer::Choices(vec![0, 2, 3]),
+            Answer::Number(-12.5),
+            Answer::Text("Libre".to_string()),
+        ];
+        for (index, answer) in answers.into_iter().enumerate() {
+            survey.answer_question(index, answer);
+        }
+
+        let shared = shared(&survey);
+        assert_eq!(shared.template_id, "t");
+        assert_eq!(shared.template_version, "1.0");
+
+        // Les réponses libres ne sont pas partagées
+        let mut expected = survey.answers();
+        expected.remove("q6");
+        assert_eq!(shared.decode(&survey), Ok(expected));
+
+        for answer in [Answer::Non, Answer::JeNeSaisPas] {
+            survey.answer_question(0, answer.clone());
+            survey.answer_question(1, Answer::JeNeSaisPas);
+            let decoded = self::shared(&survey).decode(&survey).unwrap();
+            assert_eq!(decoded["q1"], answer);
+            assert_eq!(decoded["q2"], Answer::JeNeSaisPas);
+        }
+    }
+
+    #[test]
+    fn empty_survey_round_trips() {
+        let survey = survey();
+        assert_eq!(shared(&survey).decode(&survey), Ok(HashMap::new()));
+    }
+
+    #[test]
+    fn tampered_links_are_rejected() {
+        let survey = survey();
+        survey.answer_question(0, Answer::Oui);
+        survey.answer_question(2, Answer::Choice(3));
+        let shared = shared(&survey);
+
+        let truncated = tampered(&shared, |bytes| {
+            bytes.pop();
+        });
+        assert_eq!(
+            truncated.decode(&survey),
+            Err("lien de résultats incomplet".to_string())
+        );
+
+        let trailing = tampered(&shared, |bytes| bytes.push(TAG_NONE));
+        assert_eq!(
+            trailing.decode(&survey),
+            Err("lien de résultats trop long".to_string())
+        );
+
+        let version = tampered(&shared, |bytes| bytes[0] = ENCODING_VERSION + 1);
+        assert_eq!(
+            version.decode(&survey),
+            Err("format de lien de résultats inconnu".to_string())
+        );
+
+        let unknown_tag = tampered(&shared, |bytes| bytes[5] = 42);
+        assert_eq!(
+            unknown_tag.decode(&survey),
+            Err("réponse inconnue (42)".to_string())
+        );
+
+        let unreadable = SharedAnswers {
+            payload: "!".to_string(),
+            ..shared.clone()
+        };
+        assert_eq!(
+            unreadable.decode(&survey),
+            Err("lien de résultats illisible".to_string())
+        );
+    }
+
+    #[test]
+    fn links_are_rejected_for_other_questions() {
+        let survey = survey();
+        survey.answer_question(2, Answer::Choice(3));
+        let shared = shared(&survey);
+
+        let renamed = survey_with(thematic(["x1", "q2", "q3", "q4", "q5", "q6", "q7"], 4));
+        assert_eq!(
+            shared.decode(&renamed),
+            Err("le lien a été créé pour d'autres questions".to_string())
+        );
+
+        // Mêmes identifiants, mais l'option choisie n'existe plus
+        let fewer_options = survey_with(thematic(IDS, 2));
+        assert_eq!(
+            shared.decode(&fewer_options),
+            Err("réponse invalide pour la question q3".to_string())
+        );
+    }
+
+    #[test]
+    fn fragment_requires_the_results_prefix() {
+        assert_eq!(SharedAnswers::from_fragment("#questions/t/1.0/AQ"), None);
+        assert_eq!(SharedAnswers::from_fragment("#resultats/t/1.0"), None);
+    }
+}
@@ -0,0 +1,2068 @@
+use crate::i18n::Locale;
+use crate::models::*;
+use crate::scoring::{DefaultScoring, ScoringStrategy};
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
+
+/// Catalogue et fichiers YAML des thématiques d'une langue, intégrés au binaire
+struct EmbeddedQuestions {
+    catalog: (&'static str, &'static str),
+    thematics: &'static [(&'static str, &'static str)],
+}
+
+// `EMBEDDED_FR` et `EMBEDDED_EN`, listés par `build.rs` depuis les catalogues
+include!(concat!(env!("OUT_DIR"), "/embedded_questions.rs"));
+
+/// Catalogue des questionnaires accompagné du contenu des thématiques qu'il référence
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuestionBundle {
+    /// Langue des textes du catalogue et des thématiques
+    pub locale: Locale,
+    pub catalog: QuestionCatalog,
+    /// Couples (nom de fichier, contenu YAML)
+    pub files: Vec<(String, String)>,
+}
+
+impl QuestionBundle {
+    pub fn try_from_catalog(
+        locale: Locale,
+        catalog_file: &str,
+        catalog_content: &str,
+        files: Vec<(String, String)>,
+    ) -> Result<Self, QuestionBankError> {
+        let catalog: QuestionCatalog = serde_yaml::from_str(catalog_content)
+            .map_err(|err| QuestionBankError::from_yaml(catalog_file, &err))?;
+        if catalog.templates.is_empty() {
+            return Err(QuestionBankError {
+                file: catalog_file.to_string(),
+                line: None,
+                column: None,
+                reason: "aucun questionnaire déclaré".to_string(),
+            });
+        }
+        FeedbackBand::validate(&catalog.feedback).map_err(|reason| {
+            QuestionBankError::at_key(catalog_file, catalog_content, "feedback", reason)
+        })?;
+        MaturityStage::validate(&catalog.stages).map_err(|reason| {
+            QuestionBankError::at_key(catalog_file, catalog_content, "stages", reason)
+        })?;
+        if let Some(rules) = &catalog.eligibility {
+            rules
+                .required
+                .validate()
+                .and_then(|_| rules.conditions.validate())
+                .map_err(|reason| {
+                    QuestionBankError::at_key(catalog_file, catalog_content, "eligibility", reason)
+                })?;
+        }
+
+        Ok(Self {
+            locale,
+            catalog,
+            files,
+        })
+    }
+
+    /// Lit le catalogue d'une langue puis les thématiques qu'il référence avec
+    /// `read` (chemin relatif au dossier de la langue), et valide l'ensemble
+    pub fn load(
+        locale: Locale,
+        mut read: impl FnMut(&str) -> Result<String, String>,
+    ) -> Result<Self, QuestionBankError> {
+        let dir = locale.questions_dir();
+        let mut read = |file: &str| {
+            read(file).map_err(|reason| QuestionBankError {
+                file: format!("{}{}", dir, file),
+                line: None,
+                column: None,
+                reason,
+            })
+        };
+
+        let catalog_content = read("catalog.yaml")?;
+        // Un catalogue invalide est transmis sans thématiques pour que son erreur
+        // soit signalée avec sa position par `try_from_catalog`
+        let files = match serde_yaml::from_str::<QuestionCatalog>(&catalog_content) {
+            Ok(catalog) => catalog
+                .thematic_files()
+                .into_iter()
+                .map(|file| read(file).map(|content| (file.to_string(), content)))
+                .collect::<Result<_, _>>()?,
+            Err(_) => Vec::new(),
+        };
+        let catalog_file = format!("{}catalog.yaml", dir);
+        let bundle = Self::try_from_catalog(locale, &catalog_file, &catalog_content, files)?;
+        bundle.validate()?;
+        Ok(bundle)
+    }
+
+    /// Questionnaire intégré au binaire, utilisé lorsque le chargement distant
+    /// est désactivé ou échoue
+    pub fn embedded(locale: Locale) -> Result<Self, QuestionBankError> {
+        let embedded = match locale {
+            Locale::Fr => &EMBEDDED_FR,
+            Locale::En => &EMBEDDED_EN,
+        };
+        let (catalog_file, catalog_content) = embedded.catalog;
+        let files = embedded
+            .thematics
+            .iter()
+            .map(|(name, content)| (name.to_string(), content.to_string()))
+            .collect();
+        Self::try_from_catalog(locale, catalog_file, catalog_content, files)
+    }
+
+    pub fn template(&self, template_id: &str) -> Option<&SurveyTemplate> {
+        self.catalog
+            .templates
+            .iter()
+            .find(|template| template.id == template_id)
+    }
+
+    /// Chemin du catalogue relatif au dossier `questions/`, pour les erreurs
+    fn catalog_file(&self) -> String {
+        format!("{}catalog.yaml", self.locale.questions_dir())
+    }
+
+    /// Fichiers des thématiques d'un questionnaire, dans l'ordre du catalogue
+    pub fn sources_for(
+        &self,
+        template: &SurveyTemplate,
+    ) -> Result<Vec<(String, String)>, QuestionBankError> {
+        template
+            .thematics
+            .iter()
+            .map(|file| {
+                self.files
+                    .iter()
+                    .find(|(name, _)| name == file)
+                    .cloned()
+                    .ok_or_else(|| QuestionBankError {
+                        file: self.catalog_file(),
+                        line: None,
+                        column: None,
+                        reason: format!(
+                            "fichier {} introuvable pour le questionnaire {}",
+                            file, template.id
+                        ),
+                    })
+            })
+            .collect()
+    }
+
+    /// Vérifie que chaque questionnaire du catalogue peut être construit et que
+    /// les stades de maturité et les critères d'éligibilité ne citent que des
+    /// thématiques et des questions « Oui / Non » existantes
+    pub fn validate(&self) -> Result<(), QuestionBankError> {
+        // Les identifiants de questions sont uniques sur l'ensemble des fichiers,
+        // y compris entre thématiques qui ne figurent jamais dans le même
+        // questionnaire : les réponses de soumissions différentes restent rapprochables
+        let mut question_files: HashMap<String, &str> = HashMap::new();
+        for (file, content) in &self.files {
+            let thematic_data = serde_yaml::from_str::<ThematicQuestions>(content)
+                .map_err(|err| QuestionBankError::from_yaml(file, &err))?;
+            for (position, question) in thematic_data.questions.into_iter().enumerate() {
+                if let Some(other) = question_files.insert(question.id.clone(), file) {
+                    return Err(QuestionBankError::at_question(
+                        file,
+                        content,
+                        position,
+                        format!(
+                            "identifiant de question dupliqué : {} (déjà utilisé dans {})",
+                            question.id, other
+                        ),
+                    ));
+                }
+            }
+        }
+
+        let mut thematic_ids = HashSet::new();
+        let mut yes_no_ids = HashSet::new();
+        for template in &self.catalog.templates {
+            let survey = Survey::try_from_template(self, &template.id, DefaultScoring)?;
+            thematic_ids.extend(survey.thematics.iter().map(|thematic| thematic.id.clone()));
+            yes_no_ids.extend(
+                survey
+                    .questions
+                    .iter()
+                    .filter(|question| question.kind == AnswerKind::OuiNon)
+                    .map(|question| question.id.clone()),
+            );
+        }
+
+        let rules = self
+            .catalog
+            .stages
+            .iter()
+            .map(|stage| (format!("stade {}", stage.id), &stage.requirements))
+            .chain(self.catalog.eligibility.iter().flat_map(|rules| {
+                [
+                    ("eligibility.required".to_string(), &rules.required),
+                    ("eligibility.conditions".to_string(), &rules.conditions),
+                ]
+            }));
+        for (context, requirements) in rules {
+            let unknown = requirements
+                .min_scores
+                .iter()
+                .find(|requirement| !thematic_ids.contains(&requirement.thematic))
+                .map(|requirement| format!("thématique inconnue : {}", requirement.thematic))
+                .or_else(|| {
+                    requirements
+                        .required_yes
+                        .iter()
+                        .find(|question| !yes_no_ids.contains(*question))
+                        .map(|question| format!("question « Oui / Non » inconnue : {}", question))
+                });
+            if let Some(reason) = unknown {
+                return Err(QuestionBankError {
+                    file: self.catalog_file(),
+                    line: None,
+                    column: None,
+                    reason: format!("{} : {}", context, reason),
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Scores pondérés et décompte des réponses d'une thématique ou d'une
+/// sous-dimension, le temps du calcul des résultats
+#[derive(Default)]
+struct Tally {
+    weighted_scores: Vec<(f64, f64)>,
+    stats: ThematicStats,
+}
+
+impl Tally {
+    fn record(
+        &mut self,
+        answer: Option<&Answer>,
+        skipped: bool,
+        weighted_score: Option<(f64, f64)>,
+    ) {
+        match answer {
+            Some(answer) => {
+                self.stats.answered += 1;
+                if matches!(answer, Answer::JeNeSaisPas) {
+                    self.stats.unknown += 1;
+                }
+            }
+            None => {
+                self.stats.unanswered += 1;
+                if skipped {
+                    self.stats.skipped += 1;
+                }
+            }
+        }
+        self.weighted_scores.extend(weighted_score);
+    }
+
+    /// Score agrégé selon la stratégie de scoring et détail des réponses
+    fn finish(self, scoring: &dyn ScoringStrategy) -> (Option<f64>, ThematicStats) {
+        let mut stats = self.stats;
+        let total = stats.answered + stats.unanswered;
+        stats.completion = if total == 0 {
+            0.0
+        } else {
+            stats.answered as f64 / total as f64
+        };
+        (scoring.thematic_score(&self.weighted_scores), stats)
+    }
+}
+
+#[derive(Clone)]
+pub struct Survey {
+    /// Questionnaire du catalogue dont est issu ce `Survey`
+    template: Option<SurveyTemplate>,
+    questions: Vec<Question>,
+    /// Thématiques dans l'ordre du questionnaire, avec leurs messages de retour
+    thematics: Vec<Thematic>,
+    /// Messages associés au score global, repris du catalogue
+    global_feedback: Vec<FeedbackBand>,
+    /// Stades de maturité, repris du catalogue
+    stages: Vec<MaturityStage>,
+    /// Critères d'éligibilité au programme, repris du catalogue
+    eligibility: Option<EligibilityRules>,
+    /// Langue des questions, utilisée pour les libellés des réponses
+    locale: Locale,
+    answers: Rc<RefCell<HashMap<String, Answer>>>,
+    /// Questions passées sans réponse avec « Suivant »
+    skipped: Rc<RefCell<HashSet<String>>>,
+    scoring: Rc<dyn ScoringStrategy>,
+    unanswered_policy: UnansweredPolicy,
+}
+
+impl Survey {
+    /// Construit l'un des questionnaires du catalogue
+    pub fn try_from_template(
+        bundle: &QuestionBundle,
+        template_id: &str,
+        scoring: impl ScoringStrategy + 'static,
+    ) -> Result<Self, QuestionBankError> {
+        let template = bundle
+            .template(template_id)
+            .ok_or_else(|| QuestionBankError {
+                file: bundle.catalog_file(),
+                line: None,
+                column: None,
+                reason: format!("questionnaire inconnu : {}", template_id),
+            })?;
+        let sources = bundle.sources_for(template)?;
+
+        let mut survey = Self::try_from_sources(&sources, scoring)?;
+        survey.template = Some(template.clone());
+        survey.global_feedback = bundle.catalog.feedback.clone();
+        survey.stages = bundle.catalog.stages.clone();
+        survey.eligibility = bundle.catalog.eligibility.clone();
+        survey.locale = bundle.locale;
+        Ok(survey)
+    }
+
+    /// Construit le questionnaire à partir de fichiers YAML (nom, contenu) et
+    /// d'une stratégie de scoring. Le premier fichier invalide interrompt le
+    /// chargement : une thématique n'est jamais ignorée silencieusement.
+    pub fn try_from_sources(
+        sources: &[(String, String)],
+        scoring: impl ScoringStrategy + 'static,
+    ) -> Result<Self, QuestionBankError> {
+        let mut questions = Vec::new();
+        let mut thematics: Vec<Thematic> = Vec::new();
+        let mut seen_ids = HashSet::new();
+
+        let mut parsed = sources
+            .iter()
+            .map(|(file, yaml_content)| {
+                serde_yaml::from_str::<ThematicQuestions>(yaml_content)
+                    .map(|thematic_data| (file, yaml_content, thematic_data))
+                    .map_err(|err| QuestionBankError::from_yaml(file, &err))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        // Les thématiques ayant un rang d'affichage d'abord, les autres dans l'ordre
+        // des fichiers ; le tri est stable
+        parsed.sort_by_key(|(_, _, thematic_data)| {
+            (thematic_data.order.is_none(), thematic_data.order)
+        });
+
+        for (rank, (file, yaml_content, thematic_data)) in parsed.into_iter().enumerate() {
+            let thematic_name = thematic_data.thematic.clone();
+            // Une thématique est déclarée dans un seul fichier : ses questions restent contiguës
+            if thematics
+                .iter()
+                .any(|thematic| thematic.name == thematic_name)
+            {
+                return Err(QuestionBankError::at_key(
+                    file,
+                    yaml_content,
+                    "thematic",
+                    format!("thématique déclarée deux fois : {}", thematic_name),
+                ));
+            }
+            let mut thematic = Thematic {
+                id: thematic_data.id.unwrap_or_else(|| {
+                    std::path::Path::new(file)
+                        .file_stem()
+                        .map(|stem| stem.to_string_lossy().into_owned())
+                        .unwrap_or_else(|| file.clone())
+                }),
+                name: thematic_name.clone(),
+                color: thematic_data.color.unwrap_or_else(|| {
+                    DEFAULT_THEMATIC_COLORS[rank % DEFAULT_THEMATIC_COLORS.len()].to_string()
+                }),
+                icon: thematic_data.icon,
+                intro: thematic_data.intro,
+                dimensions: thematic_data.dimensions,
+                feedback: Vec::new(),
+            };
+            thematic.validate().map_err(|(key, reason)| {
+                QuestionBankError::at_key(file, yaml_content, key, reason)
+            })?;
+            if thematics.iter().any(|other| other.id == thematic.id) {
+                return Err(QuestionBankError::at_key(
+                    file,
+                    yaml_content,
+                    "id",
+                    format!("identifiant de thématique dupliqué : {}", thematic.id),
+                ));
+            }
+
+            for (position, q_yaml) in thematic_data.questions.into_iter().enumerate() {
+                let invalid = |reason: String| {
+                    QuestionBankError::at_question(file, yaml_content, position, reason)
+                };
+
+                // Les identifiants servent de clé aux réponses : ils doivent être
+                // uniques sur l'ensemble des thématiques
+                if q_yaml.id.trim().is_empty() {
+                    return Err(invalid(format!(
+                        "question sans identifiant dans la thématique {}",
+                        thematic_name
+                    )));
+                }
+                if seen_ids.contains(&q_yaml.id) {
+                    return Err(invalid(format!(
+                        "identifiant de question dupliqué : {}",
+                        q_yaml.id
+                    )));
+                }
+                if !q_yaml.weight.is_finite() || q_yaml.weight < 0.0 {
+                    return Err(invalid(format!(
+                        "poids invalide pour la question {} : {} (nombre positif attendu)",
+                        q_yaml.id, q_yaml.weight
+                    )));
+                }
+                if let Err(reason) = q_yaml.answer.validate() {
+                    return Err(invalid(format!(
+                        "type de réponse invalide pour la question {} : {}",
+                        q_yaml.id, reason
+                    )));
+                }
+                if let Some(Err(reason)) = q_yaml.scores.as_ref().map(|s| s.validate()) {
+                    return Err(invalid(format!(
+                        "barème invalide pour la question {} : {}",
+                        q_yaml.id, reason
+                    )));
+                }
+                if let Some(Err(reason)) = q_yaml.recommendation.as_ref().map(|r| r.validate()) {
+                    return Err(invalid(format!(
+                        "recommandation invalide pour la question {} : {}",
+                        q_yaml.id, reason
+                    )));
+                }
+                // Une condition ne peut porter que sur une question déjà posée, ce qui
+                // exclut les cycles
+                for condition in &q_yaml.visible_if {
+                    if !seen_ids.contains(&condition.question) {
+                        return Err(invalid(format!(
+                            "la condition d'affichage de {} porte sur {}, qui n'est pas une question précédente",
+                            q_yaml.id, condition.question
+                        )));
+                    }
+                    let source = questions
+                        .iter()
+                        .find(|q: &&Question| q.id == condition.question)
+                        .map(|q| &q.kind);
+                    if let Some(answer) = condition
+                        .answers
+                        .iter()
+                        .find(|answer| !source.is_some_and(|kind| kind.accepts(answer)))
+                    {
+                        return Err(invalid(format!(
+                            "réponse {:?} impossible pour {} dans la condition d'affichage de {}",
+                            answer, condition.question, q_yaml.id
+                        )));
+                    }
+                }
+
+                // Lorsque la thématique a des sous-dimensions, chaque question en
+                // désigne une
+                match &q_yaml.dimension {
+                    None if !thematic.dimensions.is_empty() => {
+                        return Err(invalid(format!(
+                            "la question {} doit indiquer sa sous-dimension (dimension)",
+                            q_yaml.id
+                        )));
+                    }
+                    Some(dimension) if !thematic.dimensions.iter().any(|d| &d.id == dimension) => {
+                        return Err(invalid(format!(
+                            "sous-dimension inconnue pour la question {} : {}",
+                            q_yaml.id, dimension
+                        )));
+                    }
+                    _ => {}
+                }
+
+                seen_ids.insert(q_yaml.id.clone());
+                questions.push(Question {
+                    id: q_yaml.id,
+                    text: q_yaml.text,
+                    description: q_yaml.description,
+                    thematic: thematic_name.clone(),
+                    dimension: q_yaml.dimension,
+                    weight: q_yaml.weight,
+                    scores: q_yaml.scores,
+                    kind: q_yaml.answer,
+                    visible_if: q_yaml.visible_if,
+                    recommendation: q_yaml.recommendation,
+                });
+            }
+
+            if let Some(empty) = thematic.dimensions.iter().find(|dimension| {
+                !questions.iter().any(|q| {
+                    q.thematic == thematic_name && q.dimension.as_ref() == Some(&dimension.id)
+                })
+            }) {
+                return Err(QuestionBankError::at_key(
+                    file,
+                    yaml_content,
+                    "dimensions",
+                    format!("sous-dimension sans question : {}", empty.id),
+                ));
+            }
+
+            FeedbackBand::validate(&thematic_data.feedback).map_err(|reason| {
+                QuestionBankError::at_key(
+                    file,
+                    yaml_content,
+                    "feedback",
+                    format!("thématique {} : {}", thematic_name, reason),
+                )
+            })?;
+            thematic.feedback = thematic_data.feedback;
+            thematics.push(thematic);
+        }
+
+        Ok(Self {
+            template: None,
+            questions,
+            thematics,
+            global_feedback: Vec::new(),
+            stages: Vec::new(),
+            eligibility: None,
+            locale: Locale::default(),
+            answers: Rc::new(RefCell::new(HashMap::new())),
+            skipped: Rc::new(RefCell::new(HashSet::new())),
+            scoring: Rc::new(scoring),
+            unanswered_policy: UnansweredPolicy::default(),
+        })
+    }
+
+    /// Définit les stades de maturité utilisés dans `get_results`
+    pub fn with_stages(mut self, stages: Vec<MaturityStage>) -> Self {
+        self.stages = stages;
+        self
+    }
+
+    /// Définit les critères d'éligibilité utilisés dans `get_results`
+    pub fn with_eligibility(mut self, rules: EligibilityRules) -> Self {
+        self.eligibility = Some(rules);
+        self
+    }
+
+    /// Définit le traitement des questions sans réponse dans `get_results`
+    pub fn with_unanswered_policy(mut self, policy: UnansweredPolicy) -> Self {
+        self.unanswered_policy = policy;
+        self
+    }
+
+    pub fn template(&self) -> Option<&SurveyTemplate> {
+        self.template.as_ref()
+    }
+
+    /// Questions du questionnaire, visibles ou non, dans leur ordre d'affichage
+    pub fn questions(&self) -> &[Question] {
+        &self.questions
+    }
+
+    /// Une question est visible si toutes ses conditions d'affichage sont remplies
+    /// par les réponses données à des questions elles-mêmes visibles
+    pub fn is_visible(&self, index: usize) -> bool {
+        let answers = self.answers.borrow();
+        self.is_visible_with(index, &answers)
+    }
+
+    fn is_visible_with(&self, index: usize, answers: &HashMap<String, Answer>) -> bool {
+        let Some(question) = self.questions.get(index) else {
+            return false;
+        };
+
+        question.visible_if.iter().all(|condition| {
+            let Some(source_index) = self
+                .questions
+                .iter()
+                .position(|q| q.id == condition.question)
+            else {
+                return false;
+            };
+            self.is_visible_with(source_index, answers)
+                && answers
+                    .get(&condition.question)
+                    .map(|answer| condition.is_met(answer))
+                    .unwrap_or(false)
+        })
+    }
+
+    /// Index de la prochaine question visible après `index`
+    pub fn next_visible(&self, index: usize) -> Option<usize> {
+        (index + 1..self.questions.len()).find(|&i| self.is_visible(i))
+    }
+
+    /// Index de la question visible précédant `index`
+    pub fn previous_visible(&self, index: usize) -> Option<usize> {
+        (0..index).rev().find(|&i| self.is_visible(i))
+    }
+
+    /// Nombre de questions visibles compte tenu des réponses actuelles
+    pub fn visible_questions(&self) -> usize {
+        (0..self.questions.len())
+            .filter(|&i| self.is_visible(i))
+            .count()
+    }
+
+    /// Rang (à partir de 1) de la question parmi les questions visibles
+    pub fn visible_position(&self, index: usize) -> usize {
+        (0..=index.min(self.questions.len().saturating_sub(1)))
+            .filter(|&i| self.is_visible(i))
+            .count()
+    }
+
+    /// Métadonnées (couleur, icône, introduction) d'une thématique, par son nom
+    pub fn thematic(&self, name: &str) -> Option<&Thematic> {
+        self.thematics.iter().find(|thematic| thematic.name == name)
+    }
+
+    /// Thématiques dans l'ordre du questionnaire ; les questions d'une thématique
+    /// sont toujours contiguës
+    pub fn thematic_sections(&self) -> Vec<ThematicSection> {
+        let mut sections: Vec<ThematicSection> = Vec::new();
+        for (index, question) in self.questions.iter().enumerate() {
+            match sections.last_mut() {
+                Some(section) if section.name == question.thematic => {
+                    section.questions.end = index + 1
+                }
+                _ => sections.push(ThematicSection {
+                    name: question.thematic.clone(),
+                    questions: index..index + 1,
+                }),
+            }
+        }
+        sections
+    }
+
+    /// Questions répondues et visibles de chaque thématique
+    pub fn thematic_progress(&self) -> Vec<ThematicProgress> {
+        let answers = self.answers.borrow();
+        self.thematic_sections()
+            .into_iter()
+            .map(|section| {
+                let visible: Vec<usize> = section
+                    .questions
+                    .clone()
+                    .filter(|&index| self.is_visible_with(index, &answers))
+                    .collect();
+                ThematicProgress {
+                    answered: visible
+                        .iter()
+                        .filter(|&&index| answers.contains_key(&self.questions[index].id))
+                        .count(),
+                    total: visible.len(),
+                    first_visible: visible.first().copied(),
+                    section,
+                }
+            })
+            .collect()
+    }
+
+    pub fn get_question(&self, index: usize) -> QuestionData {
+        if let Some(question) = self.questions.get(index) {
+            let answers = self.answers.borrow();
+            self.question_data(index, question, answers.get(&question.id))
+        } else {
+            // Question par défaut si l'index est invalide
+            QuestionData {
+                question: Question {
+                    id: String::new(),
+                    text: "Question introuvable".to_string(),
+                    description: None,
+                    thematic: "".to_string(),
+                    dimension: None,
+                    weight: 0.0,
+                    scores: None,
+                    kind: AnswerKind::default(),
+                    visible_if: Vec::new(),
+                    recommendation: None,
+                },
+                index,
+                thematic: "".to_string(),
+                answer: None,
+                answer_label: None,
+                skipped: false,
+            }
+        }
+    }
+
+    pub fn answer_question(&self, index: usize, answer: Answer) {
+        if let Some(question) = self.questions.get(index) {
+            // Les réponses qui ne correspondent pas au type de la question sont ignorées
+            if question.kind.accepts(&answer) {
+                self.skipped.borrow_mut().remove(&question.id);
+                self.answers
+                    .borrow_mut()
+                    .insert(question.id.clone(), answer);
+            }
+        }
+    }
+
+    /// Efface la réponse à une question, qui redevient une question non vue
+    pub fn clear_answer(&self, index: usize) {
+        if let Some(question) = self.questions.get(index) {
+            self.answers.borrow_mut().remove(&question.id);
+            self.skipped.borrow_mut().remove(&question.id);
+        }
+    }
+
+    /// Marque une question sans réponse comme passée volontairement
+    pub fn skip_question(&self, index: usize) {
+        if let Some(question) = self.questions.get(index) {
+            if !self.answers.borrow().contains_key(&question.id) {
+                self.skipped.borrow_mut().insert(question.id.clone());
+            }
+        }
+    }
+
+    pub fn is_skipped(&self, index: usize) -> bool {
+        self.questions
+            .get(index)
+            .is_some_and(|question| self.skipped.borrow().contains(&question.id))
+    }
+
+    /// Identifiants des questions passées sans réponse
+    pub fn skipped(&self) -> HashSet<String> {
+        self.skipped.borrow().clone()
+    }
+
+    /// Restaure les questions passées d'une sauvegarde, hors questions répondues
+    /// entre-temps ou disparues du questionnaire
+    pub fn restore_skipped(&self, saved: HashSet<String>) {
+        let answers = self.answers.borrow();
+        self.skipped.borrow_mut().extend(
+            saved.into_iter().filter(|id| {
+                !answers.contains_key(id) && self.questions.iter().any(|q| &q.id == id)
+            }),
+        );
+    }
+
+    /// Copie des réponses, indexées par identifiant de question
+    pub fn answers(&self) -> HashMap<String, Answer> {
+        self.answers.borrow().clone()
+    }
+
+    /// Restaure des réponses sauvegardées ; celles qui ne correspondent plus à
+    /// une question ou à son type de réponse sont ignorées
+    pub fn restore_answers(&self, saved: HashMap<String, Answer>) {
+        let mut answers = self.answers.borrow_mut();
+        for (id, answer) in saved {
+            if let Some(question) = self.questions.iter().find(|q| q.id == id) {
+                if question.kind.accepts(&answer) {
+                    answers.insert(id, answer);
+                }
+            }
+        }
+    }
+
+    pub fn get_results(&self) -> Result<SurveyResults, IncompleteSurvey> {
+        // Décomptes par thématique (`None`) et par sous-dimension
+        let mut tallies: HashMap<(&str, Option<&str>), Tally> = HashMap::new();
+        let mut unanswered = Vec::new();
+        let mut action_plan = Vec::new();
+        let answers = self.answers.borrow();
+
+        // Calculer les scores par thématique, sans les questions masquées
+        for (index, question) in self.questions.iter().enumerate() {
+            if !self.is_visible_with(index, &answers) {
+                continue;
+            }
+
+            let answer = answers.get(&question.id);
+            let weighted_score = match answer {
+                Some(answer) => {
+                    let score = self.scoring.answer_score(question, answer);
+                    // Score inférieur à la moyenne, barème surchargé compris ; le
+                    // type de réponse ne décide qu'en l'absence de score
+                    let needs_action = match score {
+                        Some(score) => score < 50.0,
+                        None => matches!(answer, Answer::Non | Answer::JeNeSaisPas),
+                    };
+                    if let (true, Some(recommendation)) = (needs_action, &question.recommendation) {
+                        action_plan.push((
+                            question.weight,
+                            ActionItem {
+                                question_id: question.id.clone(),
+                                question: question.text.clone(),
+                                thematic: question.thematic.clone(),
+                                answer_label: question.kind.label(answer, self.locale.texts()),
+                                recommendation: recommendation.clone(),
+                            },
+                        ));
+                    }
+                    score.map(|score| (question.weight, score))
+                }
+                None => {
+                    unanswered.push(index);
+                    (self.unanswered_policy == UnansweredPolicy::CountAsZero
+                        && question.kind.is_scored())
+                    .then_some((question.weight, 0.0))
+                }
+            };
+
+            let skipped = self.skipped.borrow().contains(&question.id);
+            let thematic = question.thematic.as_str();
+            tallies
+                .entry((thematic, None))
+                .or_default()
+                .record(answer, skipped, weighted_score);
+            if let Some(dimension) = &question.dimension {
+                tallies
+                    .entry((thematic, Some(dimension.as_str())))
+                    .or_default()
+                    .record(answer, skipped, weighted_score);
+            }
+        }
+
+        if self.unanswered_policy == UnansweredPolicy::Block && !unanswered.is_empty() {
+            return Err(IncompleteSurvey { unanswered });
+        }
+
+        // Une thématique ou une sous-dimension dont toutes les questions sont
+        // masquées n'a pas de résultat
+        let thematics: Vec<ThematicResult> = self
+            .thematics
+            .iter()
+            .enumerate()
+            .filter_map(|(order, thematic)| {
+                let name = thematic.name.as_str();
+                let (score, stats) = tallies.remove(&(name, None))?.finish(&*self.scoring);
+                let dimensions = thematic
+                    .dimensions
+                    .iter()
+                    .filter_map(|dimension| {
+                        let (score, stats) = tallies
+                            .remove(&(name, Some(dimension.id.as_str())))?
+                            .finish(&*self.scoring);
+                        Some(DimensionResult {
+                            id: dimension.id.clone(),
+                            name: dimension.name.clone(),
+                            score,
+                            stats,
+                        })
+                    })
+                    .collect();
+                Some(ThematicResult {
+                    id: thematic.id.clone(),
+                    name: thematic.name.clone(),
+                    order,
+                    color: thematic.color.clone(),
+                    icon: thematic.icon.clone(),
+                    score,
+                    feedback: score
+                        .and_then(|score| FeedbackBand::message_for(&thematic.feedback, score))
+                        .map(str::to_string),
+                    stats,
+                    dimensions,
+                })
+            })
+            .collect();
+
+        let total_answered: usize = thematics.iter().map(|t| t.stats.answered).sum();
+        let total_questions: usize = thematics
+            .iter()
+            .map(|t| t.stats.answered + t.stats.unanswered)
+            .sum();
+        let completion = if total_questions == 0 {
+            0.0
+        } else {
+            100.0 * total_answered as f64 / total_questions as f64
+        };
+
+        // Le score global est la moyenne des thématiques évaluées, sans pondération
+        let scores: Vec<f64> = thematics.iter().filter_map(|t| t.score).collect();
+        let global_score =
+            (!scores.is_empty()).then(|| scores.iter().sum::<f64>() / scores.len() as f64);
+        let global_feedback = global_score
+            .and_then(|score| FeedbackBand::message_for(&self.global_feedback, score))
+            .map(str::to_string);
+
+        // Les recommandations à fort impact sur les questions les plus pondérées en
+        // premier ; à priorité égale, les moins coûteuses d'abord
+        action_plan.sort_by(|(weight_a, a), (weight_b, b)| {
+            let priority_a = weight_a * a.recommendation.impact.rank();
+            let priority_b = weight_b * b.recommendation.impact.rank();
+            priority_b.total_cmp(&priority_a).then(
+                a.recommendation
+                    .effort
+                    .rank()
+                    .total_cmp(&b.recommendation.effort.rank()),
+            )
+        });
+
+        let check = |requirements: &Requirements| {
+            self.check_requirements(requirements, &thematics, global_score, completion, &answers)
+        };
+        let maturity = (!self.stages.is_empty()).then(|| {
+            MaturityResult::from_stages(
+                self.stages
+                    .iter()
+                    .map(|stage| StageEvaluation {
+                        id: stage.id.clone(),
+                        name: stage.name.clone(),
+                        description: stage.description.clone(),
+                        criteria: check(&stage.requirements),
+                    })
+                    .collect(),
+            )
+        });
+        let eligibility = self
+            .eligibility
+            .as_ref()
+            .map(|rules| EligibilityResult::new(check(&rules.required), check(&rules.conditions)));
+
+        Ok(SurveyResults {
+            thematics,
+            global_score,
+            global_feedback,
+            action_plan: action_plan.into_iter().map(|(_, item)| item).collect(),
+            total_answered,
+            total_questions,
+            maturity,
+            eligibility,
+        })
+    }
+
+    /// Évalue des règles sur les résultats. Un critère portant sur une
+    /// thématique ou une question absente de ce questionnaire n'est pas rempli ;
+    /// une question masquée n'a pas reçu de « Oui ».
+    fn check_requirements(
+        &self,
+        requirements: &Requirements,
+        thematics: &[ThematicResult],
+        global_score: Option<f64>,
+        completion: f64,
+        answers: &HashMap<String, Answer>,
+    ) -> Vec<CriterionCheck> {
+        let not_assessable = |reference: &String| CriterionCheck {
+            criterion: Criterion::NotAssessable {
+                reference: reference.clone(),
+            },
+            met: false,
+        };
+        let global = requirements.min_global_score.map(|min| CriterionCheck {
+            criterion: Criterion::GlobalScore {
+                min,
+                score: global_score,
+            },
+            met: global_score.is_some_and(|score| score >= min),
+        });
+        let answered = requirements.min_completion.map(|min| CriterionCheck {
+            criterion: Criterion::Completion { min, completion },
+            met: completion >= min,
+        });
+        let scores = requirements.min_scores.iter().map(|requirement| {
+            let Some(thematic) = thematics
+                .iter()
+                .find(|thematic| thematic.id == requirement.thematic)
+            else {
+                return not_assessable(&requirement.thematic);
+            };
+            CriterionCheck {
+                criterion: Criterion::ThematicScore {
+                    thematic: thematic.id.clone(),
+                    name: thematic.name.clone(),
+                    min: requirement.min,
+                    score: thematic.score,
+                },
+                met: thematic.score.is_some_and(|score| score >= requirement.min),
+            }
+        });
+        let yes = requirements.required_yes.iter().map(|id| {
+            let Some(index) = self
+                .questions
+                .iter()
+                .position(|question| &question.id == id)
+            else {
+                return not_assessable(id);
+            };
+            let question = &self.questions[index];
+            CriterionCheck {
+                criterion: Criterion::AnsweredYes {
+                    question: question.id.clone(),
+                    text: question.text.clone(),
+                },
+                met: self.is_visible_with(index, answers) && answers.get(id) == Some(&Answer::Oui),
+            }
+        });
+        global
+            .into_iter()
+            .chain(answered)
+            .chain(scores)
+            .chain(yes)
+            .collect()
+    }
+
+    /// Questions visibles et leurs réponses ; les réponses données à des questions
+    /// masquées depuis sont ignorées
+    pub fn get_all_questions_with_answers(&self) -> Vec<QuestionData> {
+        let answers = self.answers.borrow();
+        self.questions
+            .iter()
+            .enumerate()
+            .filter(|(index, _)| self.is_visible_with(*index, &answers))
+            .map(|(index, question)| self.question_data(index, question, answers.get(&question.id)))
+            .collect()
+    }
+
+    fn question_data(
+        &self,
+        index: usize,
+        question: &Question,
+        answer: Option<&Answer>,
+    ) -> QuestionData {
+        QuestionData {
+            question: question.clone(),
+            index,
+            thematic: question.thematic.clone(),
+            answer: answer.cloned(),
+            answer_label: answer.map(|a| question.kind.label(a, self.locale.texts())),
+            skipped: self.skipped.borrow().contains(&question.id),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+    use std::path::{Path, PathBuf};
+
+    fn questions_dir(locale: Locale) -> PathBuf {
+        Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/../questions")).join(locale.questions_dir())
+    }
+
+    /// Questionnaire d'une langue lu et validé depuis le dossier `questions/`
+    fn read_bundle(locale: Locale) -> QuestionBundle {
+        let dir = questions_dir(locale);
+        QuestionBundle::load(locale, |file| {
+            std::fs::read_to_string(dir.join(file)).map_err(|err| err.to_string())
+        })
+        .unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    #[test]
+    fn every_question_file_is_used_by_a_template() {
+        for locale in Locale::ALL {
+            let bundle = read_bundle(locale);
+            for entry in std::fs::read_dir(questions_dir(locale)).unwrap() {
+                let file = entry.unwrap().file_name().to_string_lossy().into_owned();
+                if file.ends_with(".yaml") && file != "catalog.yaml" {
+                    assert!(
+                        bundle.files.iter().any(|(name, _)| *name == file),
+                        "{}{} n'est utilisé par aucun questionnaire du catalogue",
+                        locale.questions_dir(),
+                        file
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn embedded_questionnaire_matches_questions_dir() {
+        for locale in Locale::ALL {
+            let mut embedded = QuestionBundle::embedded(locale).unwrap();
+            let mut on_disk = read_bundle(locale);
+            embedded.files.sort();
+            on_disk.files.sort();
+            assert_eq!(embedded, on_disk);
+        }
+    }
+
+    /// Compare deux documents YAML en ignorant les textes traduisibles
+    fn assert_same_structure(
+        reference: &serde_yaml::Value,
+        translated: &serde_yaml::Value,
+        path: &str,
+    ) {
+        use serde_yaml::Value;
+        const TRANSLATED_KEYS: [&str; 11] = [
+            "text",
+            "description",
+            "label",
+            "message",
+            "title",
+            "thematic",
+            "intro",
+            "name",
+            "min_label",
+            "max_label",
+            "unit",
+        ];
+
+        match (reference, translated) {
+            (Value::Mapping(reference), Value::Mapping(translated)) => {
+                let keys = |mapping: &serde_yaml::Mapping| {
+                    mapping
+                        .keys()
+                        .filter_map(|key| key.as_str().map(str::to_string))
+                        .collect::<Vec<_>>()
+                };
+                assert_eq!(
+                    keys(reference),
+                    keys(translated),
+                    "clés différentes dans {}",
+                    path
+                );
+                for (key, value) in reference {
+                    let key = key.as_str().unwrap_or_default();
+                    if !TRANSLATED_KEYS.contains(&key) {
+                        assert_same_structure(
+                            value,
+                            &translated[key],
+                            &format!("{}.{}", path, key),
+                        );
+                    }
+                }
+            }
+            (Value::Sequence(reference), Value::Sequence(translated)) => {
+                assert_eq!(
+                    reference.len(),
+                    translated.len(),
+                    "longueurs différentes dans {}",
+                    path
+                );
+                for (index, (reference, translated)) in reference.iter().zip(translated).enumerate()
+                {
+                    assert_same_structure(reference, translated, &format!("{}[{}]", path, index));
+                }
+            }
+            _ => assert_eq!(reference, translated, "valeurs différentes dans {}", path),
+        }
+    }
+
+    #[test]
+    fn translations_mirror_the_french_questionnaire() {
+        let reference = read_bundle(Locale::Fr);
+        for locale in Locale::ALL {
+            let translated = read_bundle(locale);
+            let parse = |content: &str| serde_yaml::from_str::<serde_yaml::Value>(content).unwrap();
+
+            let catalog = |locale: Locale| {
+                std::fs::read_to_string(questions_dir(locale).join("catalog.yaml")).unwrap()
+            };
+            assert_same_structure(
+                &parse(&catalog(Locale::Fr)),
+                &parse(&catalog(locale)),
+                &format!("{}catalog.yaml", locale.questions_dir()),
+            );
+
+            assert_eq!(reference.files.len(), translated.files.len());
+            for (file, content) in &reference.files {
+                let (_, translated_content) = translated
+                    .files
+                    .iter()
+                    .find(|(name, _)| name == file)
+                    .unwrap_or_else(|| panic!("{}{} manquant", locale.questions_dir(), file));
+                assert_same_structure(
+                    &parse(content),
+                    &parse(translated_content),
+                    &format!("{}{}", locale.questions_dir(), file),
+                );
+            }
+        }
+    }
+
+    /// Construit un questionnaire à partir de fichiers YAML (nom, contenu)
+    fn load(files: &[(&str, &str)]) -> Result<Survey, QuestionBankError> {
+        let sources: Vec<(String, String)> = files
+            .iter()
+            .map(|(name, content)| (name.to_string(), content.to_string()))
+            .collect();
+        Survey::try_from_sources(&sources, DefaultScoring)
+    }
+
+    /// Questionnaire d'un seul fichier `a.yaml`
+    fn survey(yaml: &str) -> Survey {
+        load(&[("a.yaml", yaml)]).unwrap()
+    }
+
+    /// Erreur de chargement d'un seul fichier `a.yaml`
+    fn load_err(yaml: &str) -> QuestionBankError {
+        load(&[("a.yaml", yaml)]).err().unwrap()
+    }
+
+    #[test]
+    fn syntax_error_reports_file_and_position() {
+        let err = load(&[(
+            "cassé.yaml",
+            r#"thematic: "Test"
+questions:
+  - id: "a"
+    text: [
+"#,
+        )])
+        .err()
+        .unwrap();
+        assert_eq!(err.file, "cassé.yaml");
+        assert!(err.line.is_some());
+    }
+
+    #[test]
+    fn duplicate_id_reports_offending_question() {
+        let err = load(&[
+            (
+                "a.yaml",
+                r#"thematic: "A"
+questions:
+  - id: "q1"
+    text: "Q1"
+feedback:
+  - max: 100
+    message: "A"
+"#,
+            ),
+            (
+                "b.yaml",
+                r#"thematic: "B"
+questions:
+  - id: "q2"
+    text: "Q2"
+  - id: "q1"
+    text: "Q1 bis"
+"#,
+            ),
+        ])
+        .err()
+        .unwrap();
+        assert_eq!(err.file, "b.yaml");
+        assert_eq!(err.line, Some(5));
+        assert!(err.reason.contains("q1"));
+    }
+
+    #[test]
+    fn duplicate_id_in_same_file_points_at_second_occurrence() {
+        let err = load_err(
+            r#"id: "q1"
+thematic: "A"
+dimensions:
+  - id: "q1"
+    name: "D"
+questions:
+  - id: "q1"
+    text: "Q1"
+    dimension: "q1"
+  - text: "Q1 bis"
+    dimension: "q1"
+    id: "q1"
+"#,
+        );
+        assert_eq!((err.line, err.column), (Some(12), Some(5)));
+        assert!(err.reason.contains("dupliqué"));
+    }
+
+    #[test]
+    fn question_ids_are_unique_across_templates() {
+        let catalog = r#"templates:
+  - id: "un"
+    title: "Un"
+    description: "Un"
+    version: "1.0"
+    thematics: [a.yaml]
+  - id: "deux"
+    title: "Deux"
+    description: "Deux"
+    version: "1.0"
+    thematics: [b.yaml]
+feedback:
+  - max: 100
+    message: "Global"
+"#;
+        let files = vec![
+            ("a.yaml".to_string(), one_question("", "A", "q1")),
+            ("b.yaml".to_string(), one_question("", "B", "q1")),
+        ];
+        let bundle =
+            QuestionBundle::try_from_catalog(Locale::Fr, "catalog.yaml", catalog, files).unwrap();
+        // Chaque questionnaire, pris seul, est valide
+        assert!(Survey::try_from_template(&bundle, "deux", DefaultScoring).is_ok());
+
+        let err = bundle.validate().unwrap_err();
+        assert_eq!(err.file, "b.yaml");
+        assert_eq!(err.line, Some(3));
+        assert!(err.reason.contains("a.yaml"), "{}", err.reason);
+    }
+
+    #[test]
+    fn hidden_questions_are_skipped_and_not_scored() {
+        let survey = survey(
+            r#"thematic: "A"
+questions:
+  - id: "deck"
+    text: "Deck ?"
+  - id: "reseau"
+    text: "Réseau ?"
+    visible_if:
+      - question: "deck"
+        answers: ["oui"]
+  - id: "fin"
+    text: "Fin ?"
+feedback:
+  - max: 50
+    message: "Bas"
+  - max: 100
+    message: "Haut"
+"#,
+        );
+
+        survey.answer_question(0, Answer::Non);
+        assert!(!survey.is_visible(1));
+        assert_eq!(survey.next_visible(0), Some(2));
+        assert_eq!(survey.previous_visible(2), Some(0));
+        assert_eq!(survey.visible_questions(), 2);
+
+        survey.answer_question(2, Answer::Oui);
+        let results = survey.get_results().unwrap();
+        assert_eq!(results.total_questions, 2);
+        assert_eq!(results.thematics[0].score, Some(50.0));
+        assert_eq!(results.thematics[0].feedback.as_deref(), Some("Bas"));
+
+        survey.answer_question(0, Answer::Oui);
+        assert!(survey.is_visible(1));
+        assert_eq!(survey.next_visible(0), Some(1));
+    }
+
+    #[test]
+    fn condition_on_later_question_is_rejected() {
+        let err = load_err(
+            r#"thematic: "A"
+questions:
+  - id: "q1"
+    text: "Q1"
+    visible_if:
+      - question: "q2"
+        answers: ["oui"]
+  - id: "q2"
+    text: "Q2"
+"#,
+        );
+        assert_eq!(err.line, Some(3));
+    }
+
+    #[test]
+    fn condition_answer_must_fit_question_kind() {
+        let err = load_err(
+            r#"thematic: "A"
+questions:
+  - id: "stade"
+    text: "Stade ?"
+    answer:
+      type: single-choice
+      options:
+        - label: "Idée"
+          score: 0
+        - label: "MVP"
+          score: 100
+  - id: "q2"
+    text: "Q2"
+    visible_if:
+      - question: "stade"
+        answers: [!choix 1]
+  - id: "q3"
+    text: "Q3"
+    visible_if:
+      - question: "stade"
+        answers: ["oui"]
+feedback:
+  - max: 100
+    message: "A"
+"#,
+        );
+        assert!(err.reason.contains("q3"), "{}", err.reason);
+    }
+
+    #[test]
+    fn uncovered_feedback_band_is_rejected() {
+        let err = load_err(
+            r#"thematic: "A"
+questions:
+  - id: "q1"
+    text: "Q1"
+feedback:
+  - max: 50
+    message: "Bas"
+  - max: 80
+    message: "Moyen"
+"#,
+        );
+        assert_eq!(err.line, Some(5));
+        assert!(err.reason.contains("80"));
+    }
+
+    #[test]
+    fn action_plan_lists_weak_answers_by_priority() {
+        let survey = survey(
+            r#"thematic: "A"
+questions:
+  - id: "q1"
+    text: "Q1"
+    scores:
+      je-ne-sais-pas: 20
+    recommendation:
+      text: "R1"
+      impact: faible
+  - id: "q2"
+    text: "Q2"
+    recommendation:
+      text: "R2"
+      impact: eleve
+  - id: "q3"
+    text: "Q3"
+    recommendation:
+      text: "R3"
+  - id: "q4"
+    text: "Q4"
+    scores:
+      non: 80
+    recommendation:
+      text: "R4"
+      impact: eleve
+  - id: "q5"
+    text: "Q5"
+    recommendation:
+      text: "R5"
+feedback:
+  - max: 100
+    message: "A"
+"#,
+        );
+        survey.answer_question(0, Answer::JeNeSaisPas);
+        survey.answer_question(1, Answer::Non);
+        survey.answer_question(2, Answer::Oui);
+        // « Non » noté 80 par le barème de la question : rien à recommander
+        survey.answer_question(3, Answer::Non);
+        // « Je ne sais pas » vaut 50 par défaut, la moyenne
+        survey.answer_question(4, Answer::JeNeSaisPas);
+
+        let plan = survey.get_results().unwrap().action_plan;
+        let ids: Vec<&str> = plan.iter().map(|item| item.question_id.as_str()).collect();
+        assert_eq!(ids, ["q2", "q1"]);
+    }
+
+    #[test]
+    fn answers_use_stable_serialized_names() {
+        let answers = vec![
+            Answer::Oui,
+            Answer::JeNeSaisPas,
+            Answer::Likert(4),
+            Answer::Choice(2),
+            Answer::Choices(vec![0, 3]),
+            Answer::Number(12.5),
+            Answer::Text("Libre".to_string()),
+        ];
+        let json = serde_json::to_string(&answers).unwrap();
+        assert_eq!(
+            json,
+            r#"["oui","je-ne-sais-pas",{"likert":4},{"choix":2},{"choix-multiples":[0,3]},{"nombre":12.5},{"texte":"Libre"}]"#
+        );
+        assert_eq!(serde_json::from_str::<Vec<Answer>>(&json).unwrap(), answers);
+    }
+
+    #[test]
+    fn skipped_and_cleared_answers_are_reported_separately() {
+        let survey = survey(
+            r#"thematic: "A"
+questions:
+  - id: "q1"
+    text: "Q1"
+  - id: "q2"
+    text: "Q2"
+  - id: "q3"
+    text: "Q3"
+feedback:
+  - max: 100
+    message: "A"
+"#,
+        );
+        survey.answer_question(0, Answer::Oui);
+        survey.skip_question(0);
+        assert!(!survey.is_skipped(0));
+
+        survey.skip_question(1);
+        assert!(survey.is_skipped(1));
+        survey.answer_question(1, Answer::Non);
+        assert!(!survey.is_skipped(1));
+        survey.clear_answer(1);
+        survey.skip_question(1);
+
+        let stats = &survey.get_results().unwrap().thematics[0].stats;
+        assert_eq!(stats.answered, 1);
+        assert_eq!(stats.unanswered, 2);
+        assert_eq!(stats.skipped, 1);
+
+        survey.clear_answer(0);
+        assert!(survey.answers().is_empty());
+    }
+
+    #[test]
+    fn thematic_progress_follows_sections_and_visibility() {
+        let survey = load(&[
+            (
+                "a.yaml",
+                r#"thematic: "A"
+questions:
+  - id: "a1"
+    text: "A1"
+  - id: "a2"
+    text: "A2"
+    visible_if:
+      - question: "a1"
+        answers: ["oui"]
+feedback:
+  - max: 100
+    message: "A"
+"#,
+            ),
+            (
+                "b.yaml",
+                r#"thematic: "B"
+questions:
+  - id: "b1"
+    text: "B1"
+  - id: "b2"
+    text: "B2"
+  - id: "b3"
+    text: "B3"
+feedback:
+  - max: 100
+    message: "B"
+"#,
+            ),
+        ])
+        .unwrap();
+        let sections = survey.thematic_sections();
+        assert_eq!(sections[0].questions, 0..2);
+        assert_eq!(sections[1].questions, 2..5);
+
+        survey.answer_question(3, Answer::Oui);
+        let progress = survey.thematic_progress();
+        assert_eq!((progress[0].answered, progress[0].total), (0, 1));
+        assert_eq!((progress[1].answered, progress[1].total), (1, 3));
+        assert_eq!(progress[1].first_visible, Some(2));
+
+        survey.answer_question(0, Answer::Oui);
+        assert_eq!(survey.thematic_progress()[0].total, 2);
+    }
+
+    /// Thématique d'une question « Oui / Non », précédée de métadonnées `header`
+    fn one_question(header: &str, name: &str, id: &str) -> String {
+        format!(
+            r#"{}thematic: "{}"
+questions:
+  - id: "{}"
+    text: "Q"
+feedback:
+  - max: 100
+    message: "{}"
+"#,
+            header, name, id, name
+        )
+    }
+
+    #[test]
+    fn results_follow_the_authored_thematic_order() {
+        let survey = load(&[
+            ("zeta.yaml", &one_question("", "Zeta", "z1")),
+            ("alpha.yaml", &one_question("", "Alpha", "a1")),
+            ("mu.yaml", &one_question("", "Mu", "m1")),
+        ])
+        .unwrap();
+        survey.answer_question(1, Answer::Oui);
+        let results = survey.get_results().unwrap();
+
+        let order: Vec<(&str, &str, usize)> = results
+            .thematics
+            .iter()
+            .map(|t| (t.id.as_str(), t.name.as_str(), t.order))
+            .collect();
+        assert_eq!(
+            order,
+            vec![("zeta", "Zeta", 0), ("alpha", "Alpha", 1), ("mu", "Mu", 2)]
+        );
+        assert_eq!(results.thematics[1].score, Some(100.0));
+        assert_eq!(results.thematics[1].feedback.as_deref(), Some("Alpha"));
+
+        // L'ordre est conservé dans le JSON transmis à /api/contact
+        let json = serde_json::to_value(&results).unwrap();
+        assert_eq!(json["thematics"][0]["id"], "zeta");
+        assert_eq!(json["thematics"][2]["stats"]["answered"], 0);
+    }
+
+    #[test]
+    fn thematic_metadata_sets_order_and_defaults() {
+        let survey = load(&[
+            ("a.yaml", &one_question("", "A", "a1")),
+            (
+                "b.yaml",
+                &one_question(
+                    "id: \"bravo\"\norder: 2\ncolor: \"#123abc\"\nicon: \"🚀\"\nintro: \"Intro B\"\n",
+                    "B",
+                    "b1",
+                ),
+            ),
+            ("c.yaml", &one_question("order: 1\n", "C", "c1")),
+        ])
+        .unwrap();
+
+        // Les questions suivent les thématiques triées par rang
+        let ids: Vec<&str> = survey.questions().iter().map(|q| q.id.as_str()).collect();
+        assert_eq!(ids, vec!["c1", "b1", "a1"]);
+        let b = survey.thematic("B").unwrap();
+        assert_eq!(b.id, "bravo");
+        assert_eq!(b.color, "#123abc");
+        assert_eq!(b.icon.as_deref(), Some("🚀"));
+        assert_eq!(b.intro.as_deref(), Some("Intro B"));
+        let a = survey.thematic("A").unwrap();
+        assert_eq!(
+            (a.id.as_str(), a.color.as_str()),
+            ("a", DEFAULT_THEMATIC_COLORS[2])
+        );
+
+        let results = survey.get_results().unwrap();
+        assert_eq!(results.thematics[1].color, "#123abc");
+
+        let err = load_err(&one_question("color: \"rouge\"\n", "A", "a1"));
+        assert_eq!(err.line, Some(1));
+        assert!(err.reason.contains("couleur"));
+    }
+
+    #[test]
+    fn dimensions_roll_up_into_their_thematic() {
+        let yaml = |dimension_of_q3: &str| {
+            format!(
+                r#"thematic: "GTM"
+dimensions:
+  - id: "acquisition"
+    name: "Acquisition"
+  - id: "vente"
+    name: "Vente"
+questions:
+  - id: "q1"
+    text: "Q1"
+    dimension: "acquisition"
+  - id: "q2"
+    text: "Q2"
+    dimension: "acquisition"
+    weight: 3
+  - id: "q3"
+    text: "Q3"
+{}feedback:
+  - max: 100
+    message: "GTM"
+"#,
+                dimension_of_q3
+            )
+        };
+        let survey = survey(&yaml("    dimension: \"vente\"\n"));
+        survey.answer_question(0, Answer::Oui);
+        survey.answer_question(1, Answer::Non);
+        survey.answer_question(2, Answer::Oui);
+        let thematic = &survey.get_results().unwrap().thematics[0];
+
+        let dimensions: Vec<(&str, Option<f64>)> = thematic
+            .dimensions
+            .iter()
+            .map(|d| (d.id.as_str(), d.score))
+            .collect();
+        assert_eq!(
+            dimensions,
+            vec![("acquisition", Some(25.0)), ("vente", Some(100.0))]
+        );
+        assert_eq!(thematic.dimensions[0].stats.answered, 2);
+        // La thématique reste la moyenne pondérée de toutes ses questions
+        assert_eq!(thematic.score, Some(40.0));
+
+        let err = load_err(&yaml(""));
+        assert!(err.reason.contains("q3"));
+
+        let err = load_err(&yaml("    dimension: \"autre\"\n"));
+        assert!(err.reason.contains("autre"));
+    }
+
+    #[test]
+    fn maturity_stage_is_the_last_stage_reached_in_order() {
+        let stages: Vec<MaturityStage> = serde_yaml::from_str(
+            r#"- id: "ideation"
+  name: "Idéation"
+- id: "traction"
+  name: "Traction"
+  min_scores:
+    - thematic: "a"
+      min: 50
+  required_yes: ["a1"]
+- id: "scale"
+  name: "Prêt à scaler"
+  min_global_score: 80
+  min_scores:
+    - thematic: "b"
+      min: 50
+    - thematic: "absente"
+      min: 50
+"#,
+        )
+        .unwrap();
+        MaturityStage::validate(&stages).unwrap();
+        let survey = load(&[
+            (
+                "a.yaml",
+                r#"thematic: "A"
+questions:
+  - id: "a1"
+    text: "A1"
+  - id: "a2"
+    text: "A2"
+feedback:
+  - max: 100
+    message: "A"
+"#,
+            ),
+            ("b.yaml", &one_question("", "B", "b1")),
+        ])
+        .unwrap()
+        .with_stages(stages);
+        survey.answer_question(0, Answer::Oui);
+        survey.answer_question(1, Answer::Non);
+        survey.answer_question(2, Answer::Oui);
+
+        let maturity = survey.get_results().unwrap().maturity.unwrap();
+        assert_eq!(maturity.stage().unwrap().id, "traction");
+        assert_eq!(maturity.matched().len(), 2);
+        // Le score global (75 %) manque et la thématique absente ne peut pas être
+        // évaluée : le stade n'est pas atteint
+        assert_eq!(maturity.next_stage().unwrap().criteria.len(), 3);
+        assert_eq!(
+            maturity
+                .missing()
+                .iter()
+                .map(|check| &check.criterion)
+                .collect::<Vec<_>>(),
+            vec![
+                &Criterion::GlobalScore {
+                    min: 80.0,
+                    score: Some(75.0)
+                },
+                &Criterion::NotAssessable {
+                    reference: "absente".to_string()
+                }
+            ]
+        );
+
+        // Même avec toutes les réponses à « Oui », le critère non évaluable
+        // empêche d'atteindre le dernier stade
+        survey.answer_question(1, Answer::Oui);
+        let maturity = survey.get_results().unwrap().maturity.unwrap();
+        assert_eq!(maturity.stage().unwrap().id, "traction");
+        assert_eq!(maturity.missing().len(), 1);
+        survey.answer_question(1, Answer::Non);
+
+        // Sans « Oui » à a1, le score de A suffit mais la traction n'est pas atteinte
+        survey.answer_question(0, Answer::Non);
+        survey.answer_question(1, Answer::Oui);
+        let maturity = survey.get_results().unwrap().maturity.unwrap();
+        assert_eq!(maturity.stage().unwrap().id, "ideation");
+        assert!(maturity.matched().is_empty());
+        assert_eq!(maturity.missing().len(), 1);
+    }
+
+    #[test]
+    fn skipped_questions_do_not_raise_the_maturity_stage() {
+        let bundle = QuestionBundle::embedded(Locale::Fr).unwrap();
+        let survey = Survey::try_from_template(&bundle, "start-to-scale", DefaultScoring)
+            .unwrap()
+            .with_unanswered_policy(UnansweredPolicy::Exclude);
+        let yes = [
+            "bm-modele-economique",
+            "prod-feedback",
+            "bm-plan-croissance",
+            "prod-metriques-business",
+            "gtm-processus-vente",
+            "org-structure",
+        ];
+        for (index, question) in survey.questions().iter().enumerate() {
+            if yes.contains(&question.id.as_str()) {
+                survey.answer_question(index, Answer::Oui);
+            } else {
+                survey.skip_question(index);
+            }
+        }
+
+        let results = survey.get_results().unwrap();
+        // Les scores ne portent que sur les réponses « Oui »…
+        assert_eq!(results.global_score, Some(100.0));
+        // …mais trop de questions ont été passées pour dépasser l'idéation
+        let maturity = results.maturity.unwrap();
+        assert_eq!(maturity.stage().unwrap().id, "ideation");
+        assert!(maturity
+            .missing()
+            .iter()
+            .any(|check| matches!(check.criterion, Criterion::Completion { .. })));
+    }
+
+    #[test]
+    fn eligibility_verdict_depends_on_required_criteria_and_conditions() {
+        let rules: EligibilityRules = serde_yaml::from_str(
+            r#"required:
+  required_yes: ["bp"]
+conditions:
+  min_scores:
+    - thematic: "financement"
+      min: 100
+"#,
+        )
+        .unwrap();
+        let survey = survey(
+            r#"id: "financement"
+thematic: "Financement"
+questions:
+  - id: "bp"
+    text: "Business plan ?"
+  - id: "deck"
+    text: "Deck ?"
+feedback:
+  - max: 100
+    message: "F"
+"#,
+        )
+        .with_eligibility(rules);
+        let verdict = || survey.get_results().unwrap().eligibility.unwrap().verdict;
+
+        survey.answer_question(0, Answer::Non);
+        survey.answer_question(1, Answer::Oui);
+        assert_eq!(verdict(), Verdict::NotYet);
+
+        survey.answer_question(0, Answer::Oui);
+        survey.answer_question(1, Answer::Non);
+        assert_eq!(verdict(), Verdict::EligibleWithConditions);
+        let eligibility = survey.get_results().unwrap().eligibility.unwrap();
+        assert!(eligibility.required[0].met);
+        assert!(!eligibility.conditions[0].met);
+
+        survey.answer_question(1, Answer::Oui);
+        assert_eq!(verdict(), Verdict::Eligible);
+    }
+
+    #[test]
+    fn eligibility_requires_criteria_the_template_can_assess() {
+        let rules: EligibilityRules = serde_yaml::from_str(
+            r#"required:
+  min_scores:
+    - thematic: "financement"
+      min: 40
+conditions: {}
+"#,
+        )
+        .unwrap();
+        let survey =
+            survey(&one_question("id: \"impact\"\n", "Impact", "i1")).with_eligibility(rules);
+        survey.answer_question(0, Answer::Oui);
+
+        let eligibility = survey.get_results().unwrap().eligibility.unwrap();
+        assert_eq!(eligibility.verdict, Verdict::NotYet);
+        assert_eq!(
+            eligibility.required,
+            vec![CriterionCheck {
+                criterion: Criterion::NotAssessable {
+                    reference: "financement".to_string()
+                },
+                met: false
+            }]
+        );
+    }
+
+    #[test]
+    fn skipped_questions_do_not_raise_the_eligibility_verdict() {
+        let rules: EligibilityRules = serde_yaml::from_str(
+            r#"required:
+  min_completion: 80
+  min_scores:
+    - thematic: "financement"
+      min: 100
+conditions: {}
+"#,
+        )
+        .unwrap();
+        rules.required.validate().unwrap();
+        let survey = survey(
+            r#"id: "financement"
+thematic: "Financement"
+questions:
+  - id: "bp"
+    text: "Business plan ?"
+  - id: "deck"
+    text: "Deck ?"
+feedback:
+  - max: 100
+    message: "F"
+"#,
+        )
+        .with_unanswered_policy(UnansweredPolicy::Exclude)
+        .with_eligibility(rules);
+        let eligibility = || survey.get_results().unwrap().eligibility.unwrap();
+
+        // Seule la question répondue compte dans le score, mais la moitié du
+        // questionnaire a été passée
+        survey.answer_question(0, Answer::Oui);
+        survey.skip_question(1);
+        assert_eq!(eligibility().verdict, Verdict::NotYet);
+        assert_eq!(
+            eligibility().required[0],
+            CriterionCheck {
+                criterion: Criterion::Completion {
+                    min: 80.0,
+                    completion: 50.0
+                },
+                met: false
+            }
+        );
+
+        survey.answer_question(1, Answer::Oui);
+        assert_eq!(eligibility().verdict, Verdict::Eligible);
+    }
+
+    #[test]
+    fn completion_threshold_must_be_a_percentage() {
+        let requirements: Requirements = serde_yaml::from_str("min_completion: 120").unwrap();
+        assert_eq!(
+            requirements.validate(),
+            Err("seuil hors de l'intervalle 0-100 : 120".to_string())
+        );
+    }
+
+    /// Réponse valide pour la question, déterminée par `seed` ; `None` laisse la
+    /// question sans réponse
+    fn arbitrary_answer(kind: &AnswerKind, seed: u32) -> Option<Answer> {
+        if seed % 7 == 0 {
+            return None;
+        }
+        if seed % 7 == 1 && kind.is_scored() {
+            return Some(Answer::JeNeSaisPas);
+        }
+        Some(match kind {
+            AnswerKind::OuiNon => [Answer::Oui, Answer::Non][seed as usize % 2].clone(),
+            AnswerKind::Likert { .. } => Answer::Likert((seed % 5) as u8 + 1),
+            AnswerKind::SingleChoice { options } => Answer::Choice(seed as usize % options.len()),
+            AnswerKind::MultipleChoice { options } => Answer::Choices(
+                (0..options.len())
+                    .filter(|index| seed >> index & 1 == 1)
+                    .collect(),
+            ),
+            AnswerKind::Numeric { .. } => Answer::Number(seed as f64 - 1000.0),
+            AnswerKind::Text => Answer::Text(seed.to_string()),
+        })
+    }
+
+    proptest! {
+        #[test]
+        fn scores_stay_between_0_and_100(
+            seeds in prop::collection::vec(any::<u32>(), 64),
+            policy in prop_oneof![
+                Just(UnansweredPolicy::CountAsZero),
+                Just(UnansweredPolicy::Exclude),
+            ],
+        ) {
+            let bundle = QuestionBundle::embedded(Locale::Fr).unwrap();
+            for template in &bundle.catalog.templates {
+                let survey = Survey::try_from_template(&bundle, &template.id, DefaultScoring)
+                    .unwrap()
+                    .with_unanswered_policy(policy);
+                for (index, question) in survey.questions().iter().enumerate() {
+                    if let Some(answer) = arbitrary_answer(&question.kind, seeds[index % seeds.len()]) {
+                        survey.answer_question(index, answer);
+                    }
+                }
+
+                let results = survey.get_results().unwrap();
+                for thematic in &results.thematics {
+                    let score = thematic.score.unwrap_or_default();
+                    prop_assert!((0.0..=100.0).contains(&score), "score {}", score);
+                    prop_assert!((0.0..=1.0).contains(&thematic.stats.completion));
+                }
+                prop_assert!((0.0..=100.0).contains(&results.global_score.unwrap_or_default()));
+            }
+        }
+    }
+
+    #[test]
+    fn thematic_without_scored_answers_is_not_evaluated() {
+        let survey = load(&[
+            (
+                "a.yaml",
+                r#"thematic: "A"
+questions:
+  - id: "libre"
+    text: "Libre"
+    answer:
+      type: text
+feedback:
+  - max: 100
+    message: "A"
+"#,
+            ),
+            ("b.yaml", &one_question("", "B", "b1")),
+        ])
+        .unwrap()
+        .with_unanswered_policy(UnansweredPolicy::Exclude);
+        let results = survey.get_results().unwrap();
+        assert_eq!(results.thematics[0].score, None);
+        assert_eq!(results.thematics[0].feedback, None);
+        assert_eq!(results.thematics[0].stats.completion, 0.0);
+        assert_eq!(results.thematics[1].score, None);
+        assert_eq!(results.global_score, None);
+        assert_eq!(results.global_feedback, None);
+
+        // Une thématique non évaluée ne compte pas dans le score global
+        survey.answer_question(0, Answer::Text("Réponse".to_string()));
+        survey.answer_question(1, Answer::Oui);
+        let results = survey.get_results().unwrap();
+        assert_eq!(results.thematics[0].score, None);
+        assert_eq!(results.thematics[0].stats.completion, 1.0);
+        assert_eq!(results.global_score, Some(100.0));
+
+        // Sans réponse notée comptée comme zéro, la thématique est évaluée
+        survey.clear_answer(1);
+        let survey = survey.with_unanswered_policy(UnansweredPolicy::CountAsZero);
+        assert_eq!(survey.get_results().unwrap().thematics[1].score, Some(0.0));
+    }
+
+    #[test]
+    fn non_finite_weight_and_threshold_are_rejected() {
+        for weight in [".nan", ".inf", "-1"] {
+            let err = load_err(&format!(
+                "thematic: \"A\"\nquestions:\n  - id: \"q1\"\n    text: \"Q1\"\n    weight: {}\n",
+                weight
+            ));
+            assert!(err.reason.contains("poids"), "{}", err.reason);
+        }
+
+        let err = load_err(
+            r#"thematic: "A"
+questions:
+  - id: "q1"
+    text: "Q1"
+    answer:
+      type: numeric
+      thresholds:
+        - min: .nan
+          score: 100
+"#,
+        );
+        assert_eq!(err.line, Some(3));
+        assert!(err.reason.contains("palier"), "{}", err.reason);
+    }
+
+    #[test]
+    fn out_of_range_score_override_is_rejected() {
+        let err = load_err(
+            r#"thematic: "A"
+questions:
+  - id: "q1"
+    text: "Q1"
+    scores:
+      oui: 150
+feedback:
+  - max: 100
+    message: "A"
+"#,
+        );
+        assert_eq!(err.line, Some(3));
+        assert!(err.reason.contains("150"));
+    }
+}
@@ -1,30 +1,58 @@
-use crate::components::{ContactScreen, QuestionsScreen, ResultsScreen, WelcomeScreen};
-use crate::survey::Survey;
+use crate::components::{
+    ContactScreen, ErrorScreen, QuestionsScreen, ResultsScreen, ReviewScreen, WelcomeScreen,
+};
+use crate::i18n::{self, Locale};
+use crate::loader::{catalog_url, fetch_question_sources, FetchedQuestions};
+use crate::models::{Answer, QuestionBankError};
+use crate::scoring::DefaultScoring;
+use crate::share::{self, SharedAnswers};
+use crate::storage::{self, SavedSession};
+use crate::survey::{QuestionBundle, Survey};
+use serde::{Deserialize, Serialize};
 use std::rc::Rc;
 use wasm_bindgen::prelude::*;
 use wasm_bindgen_futures::spawn_local;
 use web_sys::RequestInit;
 use yew::prelude::*;
 
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum Screen {
     Welcome,
     Questions,
+    /// Récapitulatif des réponses avant le calcul des résultats
+    Review,
     Results,
     Contact,
 }
 
 pub struct App {
     screen: Screen,
+    /// Langue de l'interface et du questionnaire
+    locale: Locale,
+    /// Catalogue des questionnaires : celui intégré au binaire, remplacé par la
+    /// version téléchargée au démarrage lorsqu'elle est disponible
+    bundle: Option<QuestionBundle>,
+    questions_loading: bool,
+    /// Erreur de chargement du questionnaire, affichée à la place de l'application
+    bank_error: Option<QuestionBankError>,
+    /// Audit interrompu pouvant être repris depuis l'écran d'accueil
+    saved_session: Option<SavedSession>,
+    /// Lien de résultats reçu au démarrage, en attente du questionnaire correspondant
+    shared_answers: Option<SharedAnswers>,
+    /// Résultats ouverts depuis un lien partagé : ils ne remplacent pas l'audit sauvegardé
+    viewing_shared: bool,
     survey: Option<Rc<Survey>>,
     current_question_index: usize,
+    answers_revision: usize,
     results: Option<crate::models::SurveyResults>,
+    questions_notice: Option<String>,
     form_data: FormData,
     form_submitted: bool,
     form_error: Option<String>,
     form_submitting: bool,
 }
 
-#[derive(Clone, Default, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct FormData {
     pub startup_name: String,
     pub contact_firstname: String,
@@ -38,52 +66,199 @@ impl Component for App {
     type Message = Msg;
     type Properties = ();
 
-    fn create(_ctx: &Context<Self>) -> Self {
-        Self {
+    fn create(ctx: &Context<Self>) -> Self {
+        let locale = i18n::detect_locale();
+        i18n::apply_to_document(locale);
+        let questions_loading = fetch_questions(ctx, locale);
+
+        let (bundle, bank_error) = match QuestionBundle::embedded(locale) {
+            Ok(bundle) => (Some(bundle), None),
+            Err(err) => (None, Some(err)),
+        };
+
+        let saved_session = bundle.as_ref().and_then(find_saved_session);
+        let shared_answers = web_sys::window()
+            .and_then(|window| window.location().hash().ok())
+            .and_then(|fragment| SharedAnswers::from_fragment(&fragment));
+
+        let mut app = Self {
             screen: Screen::Welcome,
+            locale,
+            bundle,
+            questions_loading,
+            bank_error,
+            saved_session,
+            shared_answers,
+            viewing_shared: false,
             survey: None,
             current_question_index: 0,
+            answers_revision: 0,
             results: None,
+            questions_notice: None,
             form_data: FormData::default(),
             form_submitted: false,
             form_error: None,
             form_submitting: false,
-        }
+        };
+        app.open_shared_results();
+        app
     }
 
     fn update(&mut self, ctx: &Context<Self>, msg: Self::Message) -> bool {
-        match msg {
-            Msg::StartSurvey => {
-                let survey = Rc::new(Survey::new());
-                self.survey = Some(survey);
+        let should_render = match msg {
+            Msg::QuestionsLoaded(locale, (catalog_content, files)) => {
+                // Réponse à une demande antérieure à un changement de langue
+                if locale != self.locale {
+                    return false;
+                }
+                self.questions_loading = false;
+                // Valider tous les questionnaires dès leur réception plutôt qu'au
+                // démarrage de l'audit
+                let catalog_file = catalog_url(locale).unwrap_or_default();
+                match QuestionBundle::try_from_catalog(
+                    locale,
+                    &catalog_file,
+                    &catalog_content,
+                    files,
+                )
+                .and_then(|bundle| bundle.validate().map(|_| bundle))
+                {
+                    Ok(bundle) => {
+                        self.saved_session = find_saved_session(&bundle);
+                        self.bundle = Some(bundle);
+                    }
+                    // Le questionnaire intégré reste utilisé ; l'écran d'erreur est
+                    // réservé au cas où lui-même est invalide
+                    Err(err) if self.bundle.is_some() => {
+                        web_sys::console::warn_1(
+                            &format!("Questionnaire publié invalide : {}", err).into(),
+                        );
+                    }
+                    Err(err) => self.bank_error = Some(err),
+                }
+                self.open_shared_results();
+                true
+            }
+            Msg::QuestionsLoadFailed(locale, error) => {
+                if locale != self.locale {
+                    return false;
+                }
+                // Le questionnaire intégré au binaire prend le relais
+                web_sys::console::warn_1(
+                    &format!("Chargement du questionnaire impossible : {}", error).into(),
+                );
+                self.questions_loading = false;
+                self.open_shared_results();
+                true
+            }
+            Msg::SetLocale(locale) => {
+                if locale == self.locale {
+                    return false;
+                }
+                self.locale = locale;
+                i18n::apply_to_document(locale);
+                storage::save_locale(locale);
+
+                // Le questionnaire intégré de la nouvelle langue est utilisé le
+                // temps de télécharger sa version publiée
+                match QuestionBundle::embedded(locale) {
+                    Ok(bundle) => {
+                        self.saved_session = find_saved_session(&bundle);
+                        self.bundle = Some(bundle);
+                        self.bank_error = None;
+                    }
+                    Err(err) => {
+                        self.bundle = None;
+                        self.bank_error = Some(err);
+                    }
+                }
+                self.questions_loading = fetch_questions(ctx, locale);
+                true
+            }
+            Msg::StartSurvey(template_id) => {
+                if self.questions_loading {
+                    return false;
+                }
+
+                let survey = match self.build_survey(&template_id) {
+                    Ok(survey) => survey,
+                    Err(err) => {
+                        self.bank_error = Some(err);
+                        return true;
+                    }
+                };
+                self.survey = Some(Rc::new(survey));
                 self.current_question_index = 0;
                 self.screen = Screen::Questions;
                 true
             }
+            Msg::ResumeSession => {
+                if let Some(session) = self.saved_session.take() {
+                    self.resume_session(session);
+                }
+                true
+            }
+            Msg::DiscardSession => {
+                if let Some(session) = self.saved_session.take() {
+                    storage::clear_session(&session.template_id, &session.template_version);
+                }
+                true
+            }
             Msg::AnswerQuestion(answer) => {
                 if let Some(ref survey) = self.survey {
-                    survey.answer_question(self.current_question_index, &answer);
+                    survey.answer_question(self.current_question_index, answer);
                 }
+                self.answers_revision += 1;
+                self.questions_notice = None;
                 self.next_question();
                 true
             }
+            Msg::SelectAnswer(answer) => {
+                if let Some(ref survey) = self.survey {
+                    survey.answer_question(self.current_question_index, answer);
+                }
+                self.answers_revision += 1;
+                self.questions_notice = None;
+                true
+            }
+            Msg::ClearAnswer => {
+                if let Some(ref survey) = self.survey {
+                    survey.clear_answer(self.current_question_index);
+                }
+                self.answers_revision += 1;
+                true
+            }
             Msg::NextQuestion => {
+                self.skip_current_question();
                 self.next_question();
                 true
             }
             Msg::PreviousQuestion => {
-                if self.current_question_index > 0 {
-                    self.current_question_index -= 1;
+                if let Some(ref survey) = self.survey {
+                    if let Some(previous) = survey.previous_visible(self.current_question_index) {
+                        self.current_question_index = previous;
+                    }
                 }
                 true
             }
-            Msg::ShowResults => {
+            Msg::ShowReview => {
+                self.skip_current_question();
+                self.screen = Screen::Review;
+                true
+            }
+            Msg::GoToQuestion(index) => {
                 if let Some(ref survey) = self.survey {
-                    self.results = Some(survey.get_results());
-                    self.screen = Screen::Results;
+                    if survey.is_visible(index) {
+                        self.current_question_index = index;
+                        self.screen = Screen::Questions;
+                    }
                 }
                 true
             }
+            Msg::ShowResults => {
+                self.show_results();
+                true
+            }
             Msg::ShowContact => {
                 self.screen = Screen::Contact;
                 true
@@ -119,6 +294,8 @@ impl Component for App {
                 self.form_error = None;
 
                 let form_data = self.form_data.clone();
+                let locale = self.locale;
+                let texts = i18n::texts(locale);
                 let survey = self.survey.clone();
                 let results = self.results.clone();
                 let link = ctx.link().clone();
@@ -131,13 +308,32 @@ impl Component for App {
                         Vec::new()
                     };
 
-                    // Préparer les résultats (scores par thématique)
-                    let scores = if let Some(ref results) = results {
-                        results.scores.clone()
+                    // Résultats par thématique (score, message, complétion), dans
+                    // l'ordre du questionnaire
+                    let thematics = if let Some(ref results) = results {
+                        results.thematics.clone()
                     } else {
-                        std::collections::HashMap::new()
+                        Vec::new()
                     };
 
+                    // Verdict d'éligibilité et critères évalués, pour l'équipe Hub612
+                    let eligibility = results
+                        .as_ref()
+                        .and_then(|results| results.eligibility.clone());
+
+                    // Questionnaire utilisé, pour rapprocher les réponses d'une même version
+                    let template =
+                        survey
+                            .as_ref()
+                            .and_then(|survey| survey.template())
+                            .map(|template| {
+                                serde_json::json!({
+                                    "id": template.id,
+                                    "title": template.title,
+                                    "version": template.version,
+                                })
+                            });
+
                     let json_data = serde_json::json!({
                         "startup_name": form_data.startup_name,
                         "contact_firstname": form_data.contact_firstname,
@@ -145,8 +341,11 @@ impl Component for App {
                         "contact_email": form_data.contact_email,
                         "contact_phone": form_data.contact_phone,
                         "message": form_data.message,
+                        "locale": locale.code(),
+                        "template": template,
                         "questions": questions_with_answers,
-                        "scores": scores,
+                        "thematics": thematics,
+                        "eligibility": eligibility,
                     });
 
                     let json_string = json_data.to_string();
@@ -170,8 +369,7 @@ impl Component for App {
                     let request = match web_sys::Request::new_with_str_and_init(url, &opts) {
                         Ok(req) => req,
                         Err(err) => {
-                            let error_msg =
-                                format!("Erreur lors de la création de la requête: {:?}", err);
+                            let error_msg = (texts.request_error)(&format!("{:?}", err));
                             link.send_message(Msg::FormSubmitError(error_msg));
                             return;
                         }
@@ -187,7 +385,7 @@ impl Component for App {
                                 Ok(r) => r,
                                 Err(_) => {
                                     link.send_message(Msg::FormSubmitError(
-                                        "Réponse invalide".to_string(),
+                                        texts.invalid_response.to_string(),
                                     ));
                                     return;
                                 }
@@ -204,7 +402,7 @@ impl Component for App {
                                             Ok(text) => {
                                                 let text_str =
                                                     text.as_string().unwrap_or_else(|| {
-                                                        "Erreur inconnue".to_string()
+                                                        texts.unknown_error.to_string()
                                                     });
 
                                                 // Essayer de parser le JSON pour extraire le message d'erreur
@@ -233,17 +431,16 @@ impl Component for App {
                                                     text_str
                                                 }
                                             }
-                                            Err(_) => "Erreur lors de la lecture de la réponse"
-                                                .to_string(),
+                                            Err(_) => texts.read_error.to_string(),
                                         }
                                     }
-                                    Err(_) => format!("Erreur HTTP: {}", resp.status()),
+                                    Err(_) => (texts.http_error)(resp.status()),
                                 };
                                 link.send_message(Msg::FormSubmitError(error_text));
                             }
                         }
                         Err(err) => {
-                            let error_msg = format!("Erreur réseau: {:?}", err);
+                            let error_msg = (texts.network_error)(&format!("{:?}", err));
                             link.send_message(Msg::FormSubmitError(error_msg));
                         }
                     }
@@ -252,6 +449,10 @@ impl Component for App {
                 true
             }
             Msg::FormSubmitSuccess => {
+                // L'audit est transmis : il n'y a plus rien à reprendre
+                if let Some(template) = self.survey.as_ref().and_then(|s| s.template()) {
+                    storage::clear_session(&template.id, &template.version);
+                }
                 self.form_submitted = true;
                 self.form_submitting = false;
                 self.form_error = None;
@@ -263,58 +464,113 @@ impl Component for App {
                 true
             }
             Msg::GoToWelcome => {
+                if self.viewing_shared {
+                    // Ne pas rouvrir le lien partagé au prochain rechargement
+                    if let Some(window) = web_sys::window() {
+                        let _ = window.location().set_hash("");
+                    }
+                    self.viewing_shared = false;
+                }
                 self.screen = Screen::Welcome;
                 self.survey = None;
                 self.current_question_index = 0;
                 self.results = None;
+                self.questions_notice = None;
                 self.form_data = FormData::default();
                 self.form_submitted = false;
                 self.form_error = None;
                 self.form_submitting = false;
+                self.saved_session = self.bundle.as_ref().and_then(find_saved_session);
                 true
             }
-        }
+        };
+
+        self.save_session();
+        should_render
     }
 
     fn view(&self, ctx: &Context<Self>) -> Html {
+        if let Some(ref error) = self.bank_error {
+            return html! {
+                <div id="app">
+                    <ErrorScreen error={error.clone()} locale={self.locale} />
+                </div>
+            };
+        }
+
         html! {
             <div id="app">
                 {match &self.screen {
                     Screen::Welcome => html! {
-                        <WelcomeScreen on_start={ctx.link().callback(|_| Msg::StartSurvey)} />
+                        <WelcomeScreen
+                            locale={self.locale}
+                            on_locale={ctx.link().callback(Msg::SetLocale)}
+                            loading={self.questions_loading}
+                            saved_session={self.saved_session.as_ref().and_then(|session| {
+                                self.bundle.as_ref()?.template(&session.template_id).map(|t| t.title.clone())
+                            })}
+                            on_resume={ctx.link().callback(|_| Msg::ResumeSession)}
+                            on_discard={ctx.link().callback(|_| Msg::DiscardSession)}
+                            templates={self.bundle.as_ref().map(|bundle| bundle.catalog.templates.clone()).unwrap_or_default()}
+                            on_start={ctx.link().callback(Msg::StartSurvey)}
+                        />
                     },
                     Screen::Questions => {
                         if let Some(ref survey) = self.survey {
                             html! {
                                 <QuestionsScreen
+                                    locale={self.locale}
                                     survey={Rc::clone(survey)}
                                     current_index={self.current_question_index}
+                                    notice={self.questions_notice.clone()}
+                                    revision={self.answers_revision}
                                     on_answer={ctx.link().callback(Msg::AnswerQuestion)}
+                                    on_select={ctx.link().callback(Msg::SelectAnswer)}
+                                    on_clear={ctx.link().callback(|_| Msg::ClearAnswer)}
                                     on_next={ctx.link().callback(|_| Msg::NextQuestion)}
                                     on_previous={ctx.link().callback(|_| Msg::PreviousQuestion)}
+                                    on_jump={ctx.link().callback(Msg::GoToQuestion)}
+                                    on_show_results={ctx.link().callback(|_| Msg::ShowReview)}
+                                    on_go_to_welcome={ctx.link().callback(|_| Msg::GoToWelcome)}
+                                />
+                            }
+                        } else {
+                            html! { <div>{i18n::texts(self.locale).survey_not_started}</div> }
+                        }
+                    },
+                    Screen::Review => {
+                        if let Some(ref survey) = self.survey {
+                            html! {
+                                <ReviewScreen
+                                    locale={self.locale}
+                                    survey={Rc::clone(survey)}
+                                    on_edit={ctx.link().callback(Msg::GoToQuestion)}
                                     on_show_results={ctx.link().callback(|_| Msg::ShowResults)}
                                     on_go_to_welcome={ctx.link().callback(|_| Msg::GoToWelcome)}
                                 />
                             }
                         } else {
-                            html! { <div>{"Erreur: Survey non initialisé"}</div> }
+                            html! { <div>{i18n::texts(self.locale).survey_not_started}</div> }
                         }
                     },
                     Screen::Results => {
                         if let Some(ref results) = self.results {
                             html! {
                                 <ResultsScreen
+                                    locale={self.locale}
                                     results={(*results).clone()}
+                                    share_url={self.survey.as_deref().and_then(share::share_url)}
                                     on_contact={ctx.link().callback(|_| Msg::ShowContact)}
                                     on_go_to_welcome={ctx.link().callback(|_| Msg::GoToWelcome)}
                                 />
                             }
                         } else {
-                            html! { <div>{"Aucun résultat disponible"}</div> }
+                            html! { <div>{i18n::texts(self.locale).no_results}</div> }
                         }
                     },
                     Screen::Contact => html! {
                         <ContactScreen
+                            locale={self.locale}
                             form_data={self.form_data.clone()}
                             form_submitted={self.form_submitted}
                             form_error={self.form_error.clone()}
@@ -331,48 +587,216 @@ impl Component for App {
     }
 }
 
+/// Télécharge le questionnaire publié dans la langue demandée ; retourne `false`
+/// si le chargement à l'exécution est désactivé
+fn fetch_questions(ctx: &Context<App>, locale: Locale) -> bool {
+    let Some(url) = catalog_url(locale) else {
+        return false;
+    };
+    let link = ctx.link().clone();
+    spawn_local(async move {
+        match fetch_question_sources(&url).await {
+            Ok(fetched) => link.send_message(Msg::QuestionsLoaded(locale, fetched)),
+            Err(err) => link.send_message(Msg::QuestionsLoadFailed(locale, err)),
+        }
+    });
+    true
+}
+
+/// Premier questionnaire du catalogue pour lequel un audit est en cours
+fn find_saved_session(bundle: &QuestionBundle) -> Option<SavedSession> {
+    bundle
+        .catalog
+        .templates
+        .iter()
+        .find_map(storage::load_session)
+}
+
 impl App {
+    /// Sauvegarde l'audit en cours, tant qu'il n'a pas été transmis
+    fn save_session(&self) {
+        if self.screen == Screen::Welcome || self.form_submitted || self.viewing_shared {
+            return;
+        }
+        let Some(ref survey) = self.survey else {
+            return;
+        };
+        let Some(template) = survey.template() else {
+            return;
+        };
+
+        storage::save_session(&SavedSession {
+            template_id: template.id.clone(),
+            template_version: template.version.clone(),
+            answers: survey.answers(),
+            skipped: survey.skipped(),
+            current_question_index: self.current_question_index,
+            screen: self.screen,
+            form_data: self.form_data.clone(),
+        });
+    }
+
+    fn resume_session(&mut self, session: SavedSession) {
+        let survey = match self.build_survey(&session.template_id) {
+            Ok(survey) => survey,
+            Err(err) => {
+                self.bank_error = Some(err);
+                return;
+            }
+        };
+        survey.restore_answers(session.answers);
+        survey.restore_skipped(session.skipped);
+
+        self.current_question_index = if survey.is_visible(session.current_question_index) {
+            session.current_question_index
+        } else {
+            0
+        };
+        self.survey = Some(Rc::new(survey));
+        self.form_data = session.form_data;
+        self.screen = Screen::Questions;
+
+        // Les résultats ne sont pas sauvegardés : ils sont recalculés à partir des réponses
+        if matches!(session.screen, Screen::Results | Screen::Contact) {
+            self.show_results();
+            if self.screen == Screen::Results {
+                self.screen = session.screen;
+            }
+        }
+    }
+
+    /// Ouvre directement l'écran de résultats d'un lien partagé. Un lien créé
+    /// pour une autre version du questionnaire reste en attente tant que le
+    /// catalogue téléchargé n'a pas été reçu.
+    fn open_shared_results(&mut self) {
+        let Some(shared) = self.shared_answers.take() else {
+            return;
+        };
+
+        let version_matches = self
+            .bundle
+            .as_ref()
+            .and_then(|bundle| bundle.template(&shared.template_id))
+            .is_some_and(|template| template.version == shared.template_version);
+        if !version_matches {
+            if self.questions_loading {
+                self.shared_answers = Some(shared);
+            } else {
+                web_sys::console::warn_1(
+                    &format!(
+                        "Lien de résultats ignoré : questionnaire {} en version {} introuvable",
+                        shared.template_id, shared.template_version
+                    )
+                    .into(),
+                );
+            }
+            return;
+        }
+
+        let survey = self
+            .build_survey(&shared.template_id)
+            .map_err(|err| err.to_string())
+            .and_then(|survey| {
+                survey.restore_answers(shared.decode(&survey)?);
+                Ok(survey)
+            });
+        match survey {
+            Ok(survey) => {
+                self.survey = Some(Rc::new(survey));
+                self.viewing_shared = true;
+                self.show_results();
+            }
+            Err(err) => {
+                web_sys::console::warn_1(&format!("Lien de résultats ignoré : {}", err).into())
+            }
+        }
+    }
+
+    fn build_survey(&self, template_id: &str) -> Result<Survey, QuestionBankError> {
+        let bundle = self.bundle.as_ref().ok_or_else(|| QuestionBankError {
+            file: format!("{}catalog.yaml", self.locale.questions_dir()),
+            line: None,
+            column: None,
+            reason: i18n::texts(self.locale).no_questionnaire.to_string(),
+        })?;
+        // Règle par défaut : une question passée compte comme « Non », pour
+        // qu'une startup ne soit pas jugée sur ses seules réponses favorables
+        Survey::try_from_template(bundle, template_id, DefaultScoring)
+    }
+
+    /// « Suivant » sans réponse : la question est enregistrée comme passée
+    fn skip_current_question(&self) {
+        if let Some(ref survey) = self.survey {
+            survey.skip_question(self.current_question_index);
+        }
+    }
+
     fn next_question(&mut self) {
+        // Les questions masquées par leurs conditions d'affichage sont sautées
         if let Some(ref survey) = self.survey {
-            if self.current_question_index < survey.total_questions() - 1 {
-                self.current_question_index += 1;
-            } else {
-                self.results = Some(survey.get_results());
-                self.screen = Screen::Results;
+            if let Some(next) = survey.next_visible(self.current_question_index) {
+                self.current_question_index = next;
+                return;
+            }
+        }
+        // Après la dernière question, les réponses sont relues avant le calcul des résultats
+        self.screen = Screen::Review;
+    }
+
+    fn show_results(&mut self) {
+        if let Some(ref survey) = self.survey {
+            match survey.get_results() {
+                Ok(results) => {
+                    self.results = Some(results);
+                    self.questions_notice = None;
+                    self.screen = Screen::Results;
+                }
+                Err(incomplete) => {
+                    // Renvoyer vers la première question sans réponse
+                    if let Some(&first) = incomplete.unanswered.first() {
+                        self.current_question_index = first;
+                    }
+                    self.questions_notice = Some((i18n::texts(self.locale).unanswered_notice)(
+                        incomplete.unanswered.len(),
+                    ));
+                    self.screen = Screen::Questions;
+                }
             }
         }
     }
 
     fn validate_form(&self) -> Option<String> {
+        let texts = i18n::texts(self.locale);
+
         // Valider le nom de la startup
         if self.form_data.startup_name.trim().is_empty() {
-            return Some("Le nom de la startup est requis".to_string());
+            return Some(texts.startup_name_required.to_string());
         }
 
         // Valider le prénom du contact
         if self.form_data.contact_firstname.trim().is_empty() {
-            return Some("Votre prénom est requis".to_string());
+            return Some(texts.firstname_required.to_string());
         }
 
         // Valider le nom du contact
         if self.form_data.contact_lastname.trim().is_empty() {
-            return Some("Votre nom est requis".to_string());
+            return Some(texts.lastname_required.to_string());
         }
 
         // Valider l'email
         if self.form_data.contact_email.trim().is_empty() {
-            return Some("L'email est requis".to_string());
+            return Some(texts.email_required.to_string());
         }
 
         if !self.is_valid_email(&self.form_data.contact_email) {
-            return Some("Format d'email invalide".to_string());
+            return Some(texts.invalid_email.to_string());
         }
 
         // Valider le téléphone si fourni
         if !self.form_data.contact_phone.trim().is_empty()
             && !self.is_valid_phone(&self.form_data.contact_phone)
         {
-            return Some("Format de téléphone invalide. Format attendu : +33 6 12 34 56 78 ou 06 12 34 56 78".to_string());
+            return Some(texts.invalid_phone.to_string());
         }
 
         None
@@ -493,10 +917,19 @@ impl App {
 }
 
 pub enum Msg {
-    StartSurvey,
-    AnswerQuestion(String),
+    QuestionsLoaded(Locale, FetchedQuestions),
+    QuestionsLoadFailed(Locale, String),
+    SetLocale(Locale),
+    StartSurvey(String),
+    ResumeSession,
+    DiscardSession,
+    AnswerQuestion(Answer),
+    SelectAnswer(Answer),
+    ClearAnswer,
     NextQuestion,
     PreviousQuestion,
+    ShowReview,
+    GoToQuestion(usize),
     ShowResults,
     ShowContact,
     BackToResults,
@@ -1,4 +1,5 @@
 use crate::app::FormData;
+use crate::i18n::{self, Locale};
 use wasm_bindgen::JsCast;
 use yew::prelude::*;
 
@@ -77,6 +78,7 @@ fn is_valid_phone(phone: &str) -> bool {
 
 #[derive(Properties, PartialEq)]
 pub struct Props {
+    pub locale: Locale,
     pub form_data: FormData,
     pub form_submitted: bool,
     pub form_error: Option<String>,
@@ -89,6 +91,7 @@ pub struct Props {
 
 #[function_component]
 pub fn ContactScreen(props: &Props) -> Html {
+    let texts = i18n::texts(props.locale);
     let on_startup_name = {
         let callback = props.on_update.clone();
         Callback::from(move |e: web_sys::InputEvent| {
@@ -183,8 +186,8 @@ pub fn ContactScreen(props: &Props) -> Html {
                         </div>
                     </div>
                     <div class="form-success">
-                        <h3>{"✓ Merci !"}</h3>
-                        <p>{"Votre demande a été envoyée. Notre équipe vous recontactera dans les plus brefs délais."}</p>
+                        <h3>{texts.thanks_title}</h3>
+                        <p>{texts.thanks_text}</p>
                     </div>
                 </div>
             </div>
@@ -198,15 +201,15 @@ pub fn ContactScreen(props: &Props) -> Html {
                             <img src="hub612-logo.webp" alt="HUB612" />
                         </div>
                     </div>
-                    <h2>{"Contactez-nous"}</h2>
+                    <h2>{texts.contact_title}</h2>
                     <p class="contact-intro">
-                        {"Remplissez ce formulaire et notre équipe vous recontactera rapidement pour discuter du programme Start to Scale."}
+                        {texts.contact_intro}
                     </p>
 
                     {if let Some(ref error) = props.form_error {
                         html! {
                             <div class="form-error" style="background-color: #fee; color: #c33; padding: 1rem; border-radius: 4px; margin-bottom: 1rem;">
-                                <strong>{"Erreur : "}</strong>{error}
+                                <strong>{texts.error_prefix}</strong>{error}
                             </div>
                         }
                     } else {
@@ -215,7 +218,7 @@ pub fn ContactScreen(props: &Props) -> Html {
 
                     <form onsubmit={on_submit} class="contact-form">
                         <div class="form-group">
-                            <label for="startup-name">{"Nom de votre startup *"}</label>
+                            <label for="startup-name">{texts.startup_name_label}</label>
                             <input
                                 type="text"
                                 id="startup-name"
@@ -226,7 +229,7 @@ pub fn ContactScreen(props: &Props) -> Html {
                         </div>
 
                         <div class="form-group">
-                            <label for="contact-firstname">{"Votre prénom *"}</label>
+                            <label for="contact-firstname">{texts.firstname_label}</label>
                             <input
                                 type="text"
                                 id="contact-firstname"
@@ -237,7 +240,7 @@ pub fn ContactScreen(props: &Props) -> Html {
                         </div>
 
                         <div class="form-group">
-                            <label for="contact-lastname">{"Votre nom *"}</label>
+                            <label for="contact-lastname">{texts.lastname_label}</label>
                             <input
                                 type="text"
                                 id="contact-lastname"
@@ -248,7 +251,7 @@ pub fn ContactScreen(props: &Props) -> Html {
                         </div>
 
                         <div class="form-group">
-                            <label for="contact-email">{"Votre email *"}</label>
+                            <label for="contact-email">{texts.email_label}</label>
                             <input
                                 type="email"
                                 id="contact-email"
@@ -256,12 +259,12 @@ pub fn ContactScreen(props: &Props) -> Html {
                                 oninput={on_contact_email}
                                 required={true}
                                 pattern={r"[a-zA-Z0-9._%+-]+@[a-zA-Z0-9.-]+\.[a-zA-Z]{2,}"}
-                                title="Format d'email invalide (exemple: nom@exemple.com)"
+                                title={texts.email_hint}
                             />
                             {if !props.form_data.contact_email.is_empty() && !is_valid_email(&props.form_data.contact_email) {
                                 html! {
                                     <span class="field-error" style="color: #c33; font-size: 0.875rem; display: block; margin-top: 0.25rem;">
-                                        {"Format d'email invalide"}
+                                        {texts.invalid_email}
                                     </span>
                                 }
                             } else {
@@ -270,19 +273,19 @@ pub fn ContactScreen(props: &Props) -> Html {
                         </div>
 
                         <div class="form-group">
-                            <label for="contact-phone">{"Téléphone (optionnel)"}</label>
+                            <label for="contact-phone">{texts.phone_label}</label>
                             <input
                                 type="tel"
                                 id="contact-phone"
                                 value={props.form_data.contact_phone.clone()}
                                 oninput={on_contact_phone}
                                 pattern={r"[\d\s\+\-\(\)\.]{8,}"}
-                                title="Format de téléphone invalide (exemple: +33 6 12 34 56 78 ou 06 12 34 56 78)"
+                                title={texts.phone_hint}
                             />
                             {if !props.form_data.contact_phone.is_empty() && !is_valid_phone(&props.form_data.contact_phone) {
                                 html! {
                                     <span class="field-error" style="color: #c33; font-size: 0.875rem; display: block; margin-top: 0.25rem;">
-                                        {"Format de téléphone invalide. Format attendu : +33 6 12 34 56 78 ou 06 12 34 56 78"}
+                                        {texts.invalid_phone}
                                     </span>
                                 }
                             } else {
@@ -291,7 +294,7 @@ pub fn ContactScreen(props: &Props) -> Html {
                         </div>
 
                         <div class="form-group">
-                            <label for="contact-message">{"Message (optionnel)"}</label>
+                            <label for="contact-message">{texts.message_label}</label>
                             <textarea
                                 id="contact-message"
                                 value={props.form_data.message.clone()}
@@ -307,7 +310,7 @@ pub fn ContactScreen(props: &Props) -> Html {
                                 class="btn btn-secondary"
                                 disabled={props.form_submitting}
                             >
-                                {"Retour aux résultats"}
+                                {texts.back_to_results}
                             </button>
                             <button
                                 type="submit"
@@ -315,9 +318,9 @@ pub fn ContactScreen(props: &Props) -> Html {
                                 disabled={props.form_submitting}
                             >
                                 {if props.form_submitting {
-                                    "Envoi en cours..."
+                                    texts.sending
                                 } else {
-                                    "Envoyer"
+                                    texts.send
                                 }}
                             </button>
                         </div>
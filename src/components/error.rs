@@ -0,0 +1,34 @@
+use crate::i18n::{self, Locale};
+use crate::models::QuestionBankError;
+use yew::prelude::*;
+
+#[derive(Properties, PartialEq)]
+pub struct Props {
+    pub error: QuestionBankError,
+    pub locale: Locale,
+}
+
+#[function_component]
+pub fn ErrorScreen(props: &Props) -> Html {
+    let texts = i18n::texts(props.locale);
+    let location = (texts.error_location)(props.error.line, props.error.column);
+
+    html! {
+        <div class="screen active">
+            <div class="container error-container">
+                <div class="logo">
+                    <img src="hub612-logo.webp" alt="HUB612" />
+                </div>
+                <h2>{texts.bank_error_title}</h2>
+                <p class="error-intro">
+                    {texts.bank_error_intro}
+                </p>
+                <div class="form-error bank-error">
+                    <p><strong>{texts.error_file}</strong>{&props.error.file}</p>
+                    <p><strong>{texts.error_position}</strong>{location}</p>
+                    <p><strong>{texts.error_reason}</strong>{&props.error.reason}</p>
+                </div>
+            </div>
+        </div>
+    }
+}
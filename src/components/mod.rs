@@ -1,9 +1,13 @@
 pub mod contact;
+pub mod error;
 pub mod questions;
 pub mod results;
+pub mod review;
 pub mod welcome;
 
 pub use contact::ContactScreen;
+pub use error::ErrorScreen;
 pub use questions::QuestionsScreen;
 pub use results::ResultsScreen;
+pub use review::ReviewScreen;
 pub use welcome::WelcomeScreen;
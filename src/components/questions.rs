@@ -1,47 +1,56 @@
+use crate::i18n::{self, Locale, Texts};
+use crate::models::{Answer, AnswerKind};
 use crate::survey::Survey;
 use std::rc::Rc;
+use wasm_bindgen::JsCast;
 use yew::prelude::*;
 
 #[derive(Properties)]
 pub struct Props {
+    pub locale: Locale,
     pub survey: Rc<Survey>,
     pub current_index: usize,
-    pub on_answer: Callback<String>,
+    pub notice: Option<String>,
+    /// Incrémenté à chaque réponse enregistrée, pour rafraîchir l'affichage
+    /// des réponses qui ne font pas avancer le questionnaire
+    pub revision: usize,
+    pub on_answer: Callback<Answer>,
+    /// Enregistre une réponse sans passer à la question suivante
+    pub on_select: Callback<Answer>,
+    /// Efface la réponse à la question courante
+    pub on_clear: Callback<()>,
     pub on_next: Callback<()>,
     pub on_previous: Callback<()>,
+    /// Va directement à la question d'index donné (première question d'une thématique)
+    pub on_jump: Callback<usize>,
     pub on_show_results: Callback<()>,
     pub on_go_to_welcome: Callback<()>,
 }
 
 impl PartialEq for Props {
     fn eq(&self, other: &Self) -> bool {
-        Rc::ptr_eq(&self.survey, &other.survey) && self.current_index == other.current_index
+        self.locale == other.locale
+            && Rc::ptr_eq(&self.survey, &other.survey)
+            && self.current_index == other.current_index
+            && self.notice == other.notice
+            && self.revision == other.revision
     }
 }
 
 #[function_component]
 pub fn QuestionsScreen(props: &Props) -> Html {
+    let texts = i18n::texts(props.locale);
     let question_data = props.survey.get_question(props.current_index);
-    let total = props.survey.total_questions();
-    let progress = ((props.current_index + 1) as f64 / total as f64) * 100.0;
-
-    let on_answer_oui = {
-        let callback = props.on_answer.clone();
-        Callback::from(move |_| callback.emit("oui".to_string()))
-    };
-
-    let on_answer_non = {
-        let callback = props.on_answer.clone();
-        Callback::from(move |_| callback.emit("non".to_string()))
-    };
-
-    let on_answer_je_sais_pas = {
-        let callback = props.on_answer.clone();
-        Callback::from(move |_| callback.emit("je-ne-sais-pas".to_string()))
-    };
+    let current_thematic = props.survey.thematic(&question_data.thematic);
+    // La progression ne tient compte que des questions visibles
+    let total = props.survey.visible_questions();
+    let position = props.survey.visible_position(props.current_index);
+    let progress = (position as f64 / total.max(1) as f64) * 100.0;
+    let is_last = props.survey.next_visible(props.current_index).is_none();
+    let is_first = props.survey.previous_visible(props.current_index).is_none();
 
     let on_next = {
-        let callback = if props.current_index == total - 1 {
+        let callback = if is_last {
             props.on_show_results.clone()
         } else {
             props.on_next.clone()
@@ -49,6 +58,11 @@ pub fn QuestionsScreen(props: &Props) -> Html {
         Callback::from(move |_| callback.emit(()))
     };
 
+    let on_clear = {
+        let callback = props.on_clear.clone();
+        Callback::from(move |_| callback.emit(()))
+    };
+
     let on_previous = {
         let callback = props.on_previous.clone();
         Callback::from(move |_| callback.emit(()))
@@ -59,77 +73,391 @@ pub fn QuestionsScreen(props: &Props) -> Html {
         Callback::from(move |_| callback.emit(()))
     };
 
+    // Raccourcis clavier : réponses (O / N / ?, chiffres) et flèches pour naviguer
+    let on_keydown = {
+        let kind = question_data.question.kind.clone();
+        let on_answer = props.on_answer.clone();
+        let next = if is_last {
+            props.on_show_results.clone()
+        } else {
+            props.on_next.clone()
+        };
+        let previous = props.on_previous.clone();
+        Callback::from(move |e: KeyboardEvent| {
+            if e.ctrl_key() || e.meta_key() || e.alt_key() || is_text_field(&e) {
+                return;
+            }
+            match e.key().as_str() {
+                "ArrowRight" => next.emit(()),
+                "ArrowLeft" if !is_first => previous.emit(()),
+                key => match shortcut_answer(texts, &kind, key) {
+                    Some(answer) => on_answer.emit(answer),
+                    None => return,
+                },
+            }
+            e.prevent_default();
+        })
+    };
+
+    // Le focus est placé sur l'énoncé à chaque changement de question, pour les
+    // lecteurs d'écran et pour que les raccourcis restent actifs
+    let heading_ref = use_node_ref();
+    {
+        let heading_ref = heading_ref.clone();
+        use_effect_with(props.current_index, move |_| {
+            if let Some(heading) = heading_ref.cast::<web_sys::HtmlElement>() {
+                let _ = heading.focus();
+            }
+            || {}
+        });
+    }
+
     html! {
         <div class="screen active">
-            <div class="container questions-container">
+            <div class="container questions-container" onkeydown={on_keydown}>
                 <div class="questions-header">
                     <div class="header-logo" onclick={on_logo_click} style="cursor: pointer;">
                         <img src="hub612-logo.webp" alt="HUB612" />
                     </div>
                 </div>
-                <div class="progress-bar">
+                <div
+                    class="progress-bar"
+                    role="progressbar"
+                    aria-valuemin="0"
+                    aria-valuemax={total.to_string()}
+                    aria-valuenow={position.to_string()}
+                    aria-valuetext={(texts.question_progress)(position, total)}
+                >
                     <div class="progress-fill" style={format!("width: {}%", progress)}></div>
                 </div>
                 <div class="progress-text">
-                    <span>{format!("Question {} sur {}", props.current_index + 1, total)}</span>
+                    <span>{(texts.question_progress)(position, total)}</span>
                 </div>
 
-                <div class="thematic-header">
-                    <h3>{&question_data.thematic}</h3>
+                {if let Some(ref notice) = props.notice {
+                    html! {
+                        <div class="questions-notice">{notice}</div>
+                    }
+                } else {
+                    html! {}
+                }}
+
+                <div class="questions-layout">
+                    <nav class="thematic-nav" aria-label={texts.thematic_nav}>
+                        <ul>
+                            {for props.survey.thematic_progress().into_iter().map(|progress| {
+                                let current = progress.section.questions.contains(&props.current_index);
+                                let complete = progress.total > 0 && progress.answered == progress.total;
+                                let callback = props.on_jump.clone();
+                                let target = progress.first_visible;
+                                let thematic = props.survey.thematic(&progress.section.name);
+                                html! {
+                                    <li>
+                                        <button
+                                            onclick={Callback::from(move |_| {
+                                                if let Some(index) = target {
+                                                    callback.emit(index);
+                                                }
+                                            })}
+                                            class={classes!(
+                                                "thematic-nav-item",
+                                                current.then_some("current"),
+                                                complete.then_some("complete")
+                                            )}
+                                            style={thematic.map(|thematic| format!("--thematic-color: {}", thematic.color))}
+                                            disabled={target.is_none()}
+                                            aria-current={current.then_some("step")}
+                                        >
+                                            <span class="thematic-nav-name">
+                                                {for thematic.and_then(|thematic| thematic.icon.as_ref()).map(|icon| html! {
+                                                    <span class="thematic-icon" aria-hidden="true">{icon}</span>
+                                                })}
+                                                {&progress.section.name}
+                                            </span>
+                                            <span class="thematic-nav-count">
+                                                {format!("{}/{}", progress.answered, progress.total)}
+                                            </span>
+                                        </button>
+                                    </li>
+                                }
+                            })}
+                        </ul>
+                    </nav>
+
+                    <div class="questions-main">
+                        <div class="thematic-header" style={current_thematic.map(|thematic| format!("--thematic-color: {}", thematic.color))}>
+                            <h3>
+                                {for current_thematic.and_then(|thematic| thematic.icon.as_ref()).map(|icon| html! {
+                                    <span class="thematic-icon" aria-hidden="true">{icon}</span>
+                                })}
+                                {&question_data.thematic}
+                            </h3>
+                            {for current_thematic.and_then(|thematic| thematic.intro.as_ref()).map(|intro| html! {
+                                <p class="thematic-intro">{intro}</p>
+                            })}
+                        </div>
+
+                        <div class="question-container">
+                            <h2 id="question-text" ref={heading_ref} tabindex="-1">
+                                {&question_data.question.text}
+                            </h2>
+                            {if let Some(ref desc) = question_data.question.description {
+                                html! {
+                                    <p class="question-description">{desc}</p>
+                                }
+                            } else {
+                                html! {}
+                            }}
+
+                            <div
+                                role="group"
+                                aria-labelledby="question-text"
+                                aria-describedby="keyboard-hint"
+                            >
+                                {render_answers(
+                                    texts,
+                                    &question_data.question.kind,
+                                    question_data.answer.as_ref(),
+                                    &props.on_answer,
+                                    &props.on_select,
+                                    &props.on_clear,
+                                )}
+                            </div>
+
+                            {if question_data.answer.is_some() {
+                                html! {
+                                    <button onclick={on_clear} class="clear-answer-btn">
+                                        {texts.clear_answer}
+                                    </button>
+                                }
+                            } else if props.survey.is_skipped(props.current_index) {
+                                html! {
+                                    <p class="skipped-notice">{texts.skipped_question}</p>
+                                }
+                            } else {
+                                html! {}
+                            }}
+                        </div>
+
+                        <div class="navigation">
+                            <button
+                                onclick={on_previous}
+                                class="btn btn-secondary"
+                                disabled={is_first}
+                            >
+                                {texts.previous}
+                            </button>
+                            <button onclick={on_next} class="btn btn-primary">
+                                {if is_last {
+                                    texts.review_answers
+                                } else {
+                                    texts.next
+                                }}
+                            </button>
+                        </div>
+                        <p id="keyboard-hint" class="keyboard-hint">{texts.keyboard_hint}</p>
+                    </div>
                 </div>
+            </div>
+        </div>
+    }
+}
 
-                <div class="question-container">
-                    <h2>{&question_data.question.text}</h2>
-                    {if let Some(ref desc) = question_data.question.description {
+fn render_answers(
+    texts: &Texts,
+    kind: &AnswerKind,
+    current: Option<&Answer>,
+    on_answer: &Callback<Answer>,
+    on_select: &Callback<Answer>,
+    on_clear: &Callback<()>,
+) -> Html {
+    let answer_button = |answer: Answer, class: &'static str, icon: String, label: String| {
+        let selected = current == Some(&answer);
+        let callback = on_answer.clone();
+        html! {
+            <button
+                onclick={Callback::from(move |_| callback.emit(answer.clone()))}
+                class={classes!("answer-btn", if selected { "selected" } else { "" }, class)}
+                aria-pressed={selected.to_string()}
+            >
+                <span class="answer-icon">{icon}</span>
+                <span>{label}</span>
+            </button>
+        }
+    };
+    let je_ne_sais_pas = answer_button(
+        Answer::JeNeSaisPas,
+        "answer-je-ne-sais-pas",
+        "?".to_string(),
+        texts.dont_know.to_string(),
+    );
+
+    match kind {
+        AnswerKind::OuiNon => html! {
+            <div class="answers">
+                {answer_button(Answer::Oui, "answer-oui", "✓".to_string(), texts.yes.to_string())}
+                {answer_button(Answer::Non, "answer-non", "✗".to_string(), texts.no.to_string())}
+                {je_ne_sais_pas}
+            </div>
+        },
+        AnswerKind::Likert {
+            min_label,
+            max_label,
+        } => html! {
+            <>
+                <div class="answers answers-likert">
+                    {for (1..=5u8).map(|value| {
+                        answer_button(Answer::Likert(value), "answer-likert", value.to_string(), String::new())
+                    })}
+                </div>
+                <div class="likert-labels">
+                    <span>{min_label.clone().unwrap_or_default()}</span>
+                    <span>{max_label.clone().unwrap_or_default()}</span>
+                </div>
+                <div class="answers">{je_ne_sais_pas}</div>
+            </>
+        },
+        AnswerKind::SingleChoice { options } => html! {
+            <div class="answers answers-choices">
+                {for options.iter().enumerate().map(|(index, option)| {
+                    answer_button(Answer::Choice(index), "answer-choice", "•".to_string(), option.label.clone())
+                })}
+                {je_ne_sais_pas}
+            </div>
+        },
+        AnswerKind::MultipleChoice { options } => {
+            let selected: Vec<usize> = match current {
+                Some(Answer::Choices(indices)) => indices.clone(),
+                _ => Vec::new(),
+            };
+            html! {
+                <div class="answers answers-choices">
+                    {for options.iter().enumerate().map(|(index, option)| {
+                        let checked = selected.contains(&index);
+                        let mut toggled = selected.clone();
+                        if checked {
+                            toggled.retain(|i| *i != index);
+                        } else {
+                            toggled.push(index);
+                            toggled.sort_unstable();
+                        }
+                        // Décocher la dernière option efface la réponse
+                        let onclick = if toggled.is_empty() {
+                            on_clear.reform(|_| ())
+                        } else {
+                            let answer = Answer::Choices(toggled);
+                            on_select.reform(move |_| answer.clone())
+                        };
                         html! {
-                            <p class="question-description">{desc}</p>
+                            <button
+                                onclick={onclick}
+                                class={classes!("answer-btn", "answer-choice", if checked { "selected" } else { "" })}
+                                aria-pressed={checked.to_string()}
+                            >
+                                <span class="answer-icon">{if checked { "☑" } else { "☐" }}</span>
+                                <span>{&option.label}</span>
+                            </button>
                         }
-                    } else {
-                        html! {}
-                    }}
-
-                    <div class="answers">
-                        <button
-                            onclick={on_answer_oui}
-                            class={classes!("answer-btn", if question_data.answer.as_ref().map(|a| a == "oui").unwrap_or(false) { "selected" } else { "" }, "answer-oui")}
-                        >
-                            <span class="answer-icon">{"✓"}</span>
-                            <span>{"Oui"}</span>
-                        </button>
-                        <button
-                            onclick={on_answer_non}
-                            class={classes!("answer-btn", if question_data.answer.as_ref().map(|a| a == "non").unwrap_or(false) { "selected" } else { "" }, "answer-non")}
-                        >
-                            <span class="answer-icon">{"✗"}</span>
-                            <span>{"Non"}</span>
-                        </button>
-                        <button
-                            onclick={on_answer_je_sais_pas}
-                            class={classes!("answer-btn", if question_data.answer.as_ref().map(|a| a == "je-ne-sais-pas").unwrap_or(false) { "selected" } else { "" }, "answer-je-ne-sais-pas")}
-                        >
-                            <span class="answer-icon">{"?"}</span>
-                            <span>{"Je ne sais pas"}</span>
-                        </button>
-                    </div>
+                    })}
                 </div>
-
-                <div class="navigation">
-                    <button
-                        onclick={on_previous}
-                        class="btn btn-secondary"
-                        disabled={props.current_index == 0}
-                    >
-                        {"Précédent"}
-                    </button>
-                    <button onclick={on_next} class="btn btn-primary">
-                        {if props.current_index == total - 1 {
-                            "Voir les résultats"
+            }
+        }
+        AnswerKind::Numeric { unit, .. } => {
+            let value = match current {
+                Some(Answer::Number(value)) => value.to_string(),
+                _ => String::new(),
+            };
+            let on_change = {
+                let callback = on_select.clone();
+                Callback::from(move |e: Event| {
+                    if let Some(target) = e.target() {
+                        if let Ok(input) = target.dyn_into::<web_sys::HtmlInputElement>() {
+                            if let Ok(number) = input.value().trim().replace(',', ".").parse() {
+                                callback.emit(Answer::Number(number));
+                            }
+                        }
+                    }
+                })
+            };
+            html! {
+                <>
+                    <div class="answer-input">
+                        <input
+                            type="number"
+                            step="any"
+                            value={value}
+                            onchange={on_change}
+                            aria-labelledby="question-text"
+                        />
+                        {if let Some(unit) = unit {
+                            html! { <span class="answer-unit">{unit}</span> }
                         } else {
-                            "Suivant"
+                            html! {}
                         }}
-                    </button>
+                    </div>
+                    <div class="answers">{je_ne_sais_pas}</div>
+                </>
+            }
+        }
+        AnswerKind::Text => {
+            let value = match current {
+                Some(Answer::Text(text)) => text.clone(),
+                _ => String::new(),
+            };
+            let on_change = {
+                let callback = on_select.clone();
+                let on_clear = on_clear.clone();
+                Callback::from(move |e: Event| {
+                    if let Some(target) = e.target() {
+                        if let Ok(textarea) = target.dyn_into::<web_sys::HtmlTextAreaElement>() {
+                            // Un texte vide efface la réponse
+                            let text = textarea.value();
+                            if text.trim().is_empty() {
+                                on_clear.emit(());
+                            } else {
+                                callback.emit(Answer::Text(text));
+                            }
+                        }
+                    }
+                })
+            };
+            html! {
+                <div class="answer-input">
+                    <textarea
+                        rows="4"
+                        value={value}
+                        onchange={on_change}
+                        aria-labelledby="question-text"
+                    ></textarea>
                 </div>
-            </div>
-        </div>
+            }
+        }
+    }
+}
+
+/// Les raccourcis sont désactivés pendant la saisie d'un nombre ou d'un texte
+fn is_text_field(e: &KeyboardEvent) -> bool {
+    e.target().is_some_and(|target| {
+        target.dyn_ref::<web_sys::HtmlInputElement>().is_some()
+            || target.dyn_ref::<web_sys::HtmlTextAreaElement>().is_some()
+    })
+}
+
+/// Réponse associée à une touche : O / N (Y / N en anglais), « ? », et les
+/// chiffres pour les échelles et les choix uniques
+fn shortcut_answer(texts: &Texts, kind: &AnswerKind, key: &str) -> Option<Answer> {
+    let key = key.to_lowercase();
+    if key == "?" {
+        return (*kind != AnswerKind::Text).then_some(Answer::JeNeSaisPas);
+    }
+    let digit = key.parse::<usize>().ok().filter(|digit| *digit >= 1);
+    match kind {
+        AnswerKind::OuiNon if key == texts.yes_key => Some(Answer::Oui),
+        AnswerKind::OuiNon if key == texts.no_key => Some(Answer::Non),
+        AnswerKind::Likert { .. } => digit.filter(|d| *d <= 5).map(|d| Answer::Likert(d as u8)),
+        AnswerKind::SingleChoice { options } => digit
+            .filter(|d| *d <= options.len())
+            .map(|d| Answer::Choice(d - 1)),
+        _ => None,
     }
 }
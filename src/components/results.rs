@@ -1,79 +1,56 @@
-use crate::models::SurveyResults;
+use crate::i18n::{self, Locale, Texts};
+use crate::models::{
+    ActionItem, CriterionCheck, EligibilityResult, MaturityResult, SurveyResults, ThematicResult,
+    Verdict,
+};
 use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::{spawn_local, JsFuture};
 use web_sys::HtmlCanvasElement;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
 pub struct Props {
+    pub locale: Locale,
     pub results: SurveyResults,
+    /// Lien permettant de rouvrir ces résultats, `None` s'il ne peut être construit
+    pub share_url: Option<String>,
     pub on_contact: Callback<()>,
     pub on_go_to_welcome: Callback<()>,
 }
 
-fn get_global_message(score: f64) -> &'static str {
-    if score <= 20.0 {
-        "Votre startup a encore beaucoup de potentiel à développer ! Le programme Start to Scale vous accompagnera pour structurer votre croissance et accélérer votre développement."
-    } else if score <= 40.0 {
-        "Vous êtes sur la bonne voie, mais il reste des étapes importantes à franchir. Le programme Start to Scale peut vous aider à identifier les priorités et à structurer votre approche pour passer à l'échelle."
-    } else if score <= 60.0 {
-        "Vous avez de solides bases ! Le programme Start to Scale vous permettra d'optimiser vos processus et de renforcer les domaines qui nécessitent encore de l'attention pour accélérer votre croissance."
-    } else if score <= 80.0 {
-        "Félicitations, vous êtes bien avancé ! Le programme Start to Scale vous aidera à peaufiner les derniers détails et à maximiser votre potentiel de croissance. Même les meilleurs ont toujours des axes d'amélioration."
-    } else {
-        "Impressionnant ! Vous avez une maturité remarquable. Le programme Start to Scale vous accompagnera pour maintenir cette excellence, anticiper les défis du scaling et continuer à évoluer. Même au top, il y a toujours des opportunités d'optimisation !"
-    }
-}
-
-fn get_feedback_message(thematic: &str, score: f64) -> &'static str {
-    let score_range = if score <= 20.0 {
-        0
-    } else if score <= 40.0 {
-        1
-    } else if score <= 60.0 {
-        2
-    } else if score <= 80.0 {
-        3
-    } else {
-        4
-    };
-
-    match (thematic, score_range) {
-        ("Business Model", 0) => "Votre modèle économique est un peu comme un GPS sans signal : vous savez où vous voulez aller, mais le chemin reste flou. Pas de panique, on va tracer la route ensemble !",
-        ("Business Model", 1) => "Votre business model commence à prendre forme, mais il manque encore quelques pièces du puzzle. C'est comme un IKEA sans notice : faisable, mais plus long !",
-        ("Business Model", 2) => "Vous avez une base solide, mais il reste des zones d'ombre. C'est comme avoir une recette sans les quantités exactes : ça peut marcher, mais c'est risqué !",
-        ("Business Model", 3) => "Votre modèle économique est bien structuré ! Il ne manque plus que quelques ajustements pour passer à la vitesse supérieure. On dirait presque un pro !",
-        ("Business Model", 4) => "Remarquable ! Votre business model est solide comme un roc. Vous êtes prêt à scaler, mais même les meilleurs ont toujours des axes d'amélioration !",
-
-        ("Produit", 0) => "Votre produit semble être en mode 'stealth mode' : invisible, même pour vous ! Il est temps de sortir de l'ombre et de voir ce qui se passe vraiment.",
-        ("Produit", 1) => "Vous commencez à avoir une idée de ce qui se passe dans votre produit, mais c'est encore un peu flou. C'est comme regarder à travers une vitre embuée : on devine, mais on ne voit pas tout !",
-        ("Produit", 2) => "Vous avez mis en place quelques outils de suivi, mais il manque encore des pièces du puzzle. C'est comme avoir un tableau de bord avec la moitié des voyants éteints !",
-        ("Produit", 3) => "Chapeau ! Votre produit est bien instrumenté. Vous avez une bonne vision de ce qui se passe, avec juste quelques angles morts à éclaircir.",
-        ("Produit", 4) => "Parfait ! Votre produit est sous surveillance rapprochée. Vous savez tout (ou presque) de ce qui s'y passe. Un vrai pro de la data !",
-
-        ("Go-to-Market", 0) => "Votre stratégie go-to-market est un peu comme lancer une bouteille à la mer : vous espérez que quelqu'un la trouvera, mais vous ne savez pas qui ni quand !",
-        ("Go-to-Market", 1) => "Vous avez quelques idées sur comment aller au marché, mais c'est encore un peu au feeling. C'est comme naviguer sans boussole : ça peut marcher, mais c'est risqué !",
-        ("Go-to-Market", 2) => "Votre go-to-market prend forme, mais il manque encore de la structure. C'est comme avoir une carte sans légende : vous savez où vous êtes, mais pas comment arriver à destination !",
-        ("Go-to-Market", 3) => "Votre stratégie go-to-market est bien rodée ! Vous avez les bons outils et les bons indicateurs. Il ne reste plus qu'à optimiser pour passer à la vitesse supérieure.",
-        ("Go-to-Market", 4) => "Formidable ! Votre go-to-market est une machine bien huilée. Vous savez exactement où vous allez et comment y arriver. Un vrai stratège !",
-
-        ("Organisation", 0) => "Votre organisation ressemble un peu à une ruche sans reine : tout le monde bouge, mais personne ne sait vraiment qui fait quoi ! Il est temps de structurer tout ça.",
-        ("Organisation", 1) => "Vous avez commencé à organiser les choses, mais c'est encore un peu le bazar. C'est comme un tiroir à chaussettes : on trouve parfois, mais c'est rarement au bon endroit !",
-        ("Organisation", 2) => "Votre organisation a une structure, mais elle pourrait être plus claire. C'est comme avoir un organigramme écrit sur un post-it : ça existe, mais c'est fragile !",
-        ("Organisation", 3) => "Félicitations ! Votre organisation est bien structurée. Les rôles sont clairs et les processus en place. Il ne reste plus qu'à peaufiner les détails.",
-        ("Organisation", 4) => "Exemplaire ! Votre organisation est au top. Tout est bien défini, documenté et rodé. Vous êtes prêt à scaler sans perdre en efficacité !",
-
-        ("Financement", 0) => "Votre stratégie de financement est un peu comme chercher une aiguille dans une botte de foin : vous savez qu'elle existe, mais vous ne savez pas où la chercher !",
-        ("Financement", 1) => "Vous avez quelques idées sur le financement, mais c'est encore flou. C'est comme avoir un compte en banque sans savoir combien il contient : vous espérez que c'est suffisant !",
-        ("Financement", 2) => "Votre approche du financement est en cours de structuration. Vous avez les bases, mais il manque encore quelques éléments clés pour convaincre les investisseurs.",
-        ("Financement", 3) => "Bien joué ! Votre stratégie de financement est solide. Vous avez les bons outils et les bons arguments. Il ne reste plus qu'à peaufiner pour maximiser vos chances.",
-        ("Financement", 4) => "Exceptionnel ! Votre stratégie de financement est au point. Vous êtes prêt à lever des fonds comme un pro. Les investisseurs vont se battre pour vous !",
-
-        _ => "Votre score indique qu'il y a encore du travail à faire, mais c'est normal ! Chaque startup a ses défis à relever.",
+fn render_action_item(texts: &Texts, item: &ActionItem) -> Html {
+    let recommendation = &item.recommendation;
+    html! {
+        <li class="action-item">
+            <div class="action-header">
+                <span class="action-thematic">{&item.thematic}</span>
+                <span class="action-tags">
+                    {(texts.action_tags)(recommendation.impact, recommendation.effort)}
+                </span>
+            </div>
+            <p class="action-question">
+                {&item.question}
+                <span class="action-answer">{format!(" — {}", item.answer_label)}</span>
+            </p>
+            <p class="action-text">{&recommendation.text}</p>
+            if !recommendation.resources.is_empty() {
+                <ul class="action-resources">
+                    {for recommendation.resources.iter().map(|resource| html! {
+                        <li>
+                            <a href={resource.url.clone()} target="_blank" rel="noopener noreferrer">
+                                {&resource.label}
+                            </a>
+                        </li>
+                    })}
+                </ul>
+            }
+        </li>
     }
 }
 
 #[function_component]
 pub fn ResultsScreen(props: &Props) -> Html {
+    let texts = i18n::texts(props.locale);
     let canvas_ref = use_node_ref();
     let on_contact = {
         let callback = props.on_contact.clone();
@@ -85,29 +62,69 @@ pub fn ResultsScreen(props: &Props) -> Html {
         Callback::from(move |_| callback.emit(()))
     };
 
+    let copied = use_state(|| false);
+    let on_copy_link = {
+        let share_url = props.share_url.clone();
+        let copied = copied.clone();
+        Callback::from(move |_| {
+            let (Some(url), Some(window)) = (share_url.clone(), web_sys::window()) else {
+                return;
+            };
+            let promise = window.navigator().clipboard().write_text(&url);
+            let copied = copied.clone();
+            spawn_local(async move {
+                if JsFuture::from(promise).await.is_ok() {
+                    copied.set(true);
+                }
+            });
+        })
+    };
+
+    // Thématique dont les sous-dimensions sont affichées
+    let selected = use_state(|| None::<usize>);
+    let toggle_thematic = {
+        let selected = selected.clone();
+        Callback::from(move |index: usize| {
+            selected.set(if *selected == Some(index) {
+                None
+            } else {
+                Some(index)
+            });
+        })
+    };
+    let on_radar_click = {
+        let canvas_ref = canvas_ref.clone();
+        let thematics = props.results.thematics.clone();
+        let toggle_thematic = toggle_thematic.clone();
+        Callback::from(move |event: MouseEvent| {
+            let Some(canvas) = canvas_ref.cast::<HtmlCanvasElement>() else {
+                return;
+            };
+            if let Some(index) = axis_at(&canvas, &event, thematics.len())
+                .filter(|&index| !thematics[index].dimensions.is_empty())
+            {
+                toggle_thematic.emit(index);
+            }
+        })
+    };
+    let has_dimensions = props
+        .results
+        .thematics
+        .iter()
+        .any(|thematic| !thematic.dimensions.is_empty());
+
     {
         let canvas_ref = canvas_ref.clone();
-        let scores = props.results.scores.clone();
+        let thematics = props.results.thematics.clone();
+        let selected = *selected;
         use_effect(move || {
             if let Some(canvas) = canvas_ref.cast::<HtmlCanvasElement>() {
-                draw_radar_chart(&canvas, &scores);
+                draw_radar_chart(&canvas, texts, &thematics, selected);
             }
             || {}
         });
     }
 
-    // Trier les thématiques pour un affichage cohérent
-    let mut thematics: Vec<(&String, &f64)> = props.results.scores.iter().collect();
-    thematics.sort_by(|a, b| a.0.cmp(b.0));
-
-    // Calculer le score global (moyenne de tous les scores)
-    let global_score = if props.results.scores.is_empty() {
-        0.0
-    } else {
-        let sum: f64 = props.results.scores.values().sum();
-        sum / props.results.scores.len() as f64
-    };
-
     html! {
         <div class="screen active results-screen">
             <div class="container results-container">
@@ -116,40 +133,121 @@ pub fn ResultsScreen(props: &Props) -> Html {
                         <img src="hub612-logo.webp" alt="HUB612" />
                     </div>
                 </div>
-                <h2>{"Vos résultats"}</h2>
+                <h2>{texts.results_title}</h2>
                 <p class="results-intro">
-                    {"Voici votre profil de maturité sur les différentes thématiques du programme Start to Scale."}
+                    {texts.results_intro}
                 </p>
 
                 <div class="radar-chart-container">
                     <div class="global-score">
-                        <span class="global-score-label">{"Score global"}</span>
-                        <span class="global-score-value">{format!("{}%", global_score.round() as u32)}</span>
-                        <p class="global-score-message">{get_global_message(global_score)}</p>
+                        <span class="global-score-label">{texts.global_score}</span>
+                        <span class="global-score-value">{(texts.score)(props.results.global_score)}</span>
+                        {for props.results.global_feedback.iter().map(|message| html! {
+                            <p class="global-score-message">{message}</p>
+                        })}
                     </div>
-                    <h3>{"Analyse détaillée"}</h3>
-                    <canvas ref={canvas_ref}></canvas>
+                    {for props.results.maturity.iter().map(|maturity| render_maturity(texts, maturity))}
+                    <h3>{texts.detailed_analysis}</h3>
+                    <canvas
+                        ref={canvas_ref}
+                        class={classes!(has_dimensions.then_some("drilldown"))}
+                        onclick={on_radar_click}
+                        role="img"
+                        aria-label={texts.radar_label}
+                        aria-describedby="radar-data"
+                    ></canvas>
+                    if has_dimensions {
+                        <p class="radar-hint">{texts.radar_drilldown_hint}</p>
+                    }
+                    <div aria-live="polite">
+                        {for selected
+                            .and_then(|index| props.results.thematics.get(index))
+                            .map(|thematic| render_dimensions(texts, thematic, {
+                                let selected = selected.clone();
+                                Callback::from(move |_| selected.set(None))
+                            }))}
+                    </div>
+                    // Alternative textuelle du graphique, lue par les lecteurs d'écran
+                    <table id="radar-data" class="visually-hidden">
+                        <caption>{texts.radar_table_caption}</caption>
+                        <thead>
+                            <tr>
+                                <th scope="col">{texts.thematic_column}</th>
+                                <th scope="col">{texts.score_column}</th>
+                            </tr>
+                        </thead>
+                        <tbody>
+                            {for props.results.thematics.iter().map(|thematic| html! {
+                                <tr>
+                                    <th scope="row">{&thematic.name}</th>
+                                    <td>{(texts.score)(thematic.score)}</td>
+                                </tr>
+                            })}
+                        </tbody>
+                    </table>
                     <div class="feedback-list">
-                        {for thematics.iter().map(|(thematic, score)| {
-                            let message = get_feedback_message(thematic, **score);
-                            html! {
-                                <div class="feedback-item">
-                                    <div class="feedback-header">
-                                        <h4>{thematic}</h4>
-                                        <span class="feedback-score">{format!("{}%", score.round() as u32)}</span>
-                                    </div>
-                                    <p class="feedback-message">{message}</p>
+                        {for props.results.thematics.iter().enumerate().map(|(index, thematic)| html! {
+                            <div class="feedback-item" style={format!("--thematic-color: {}", thematic.color)}>
+                                <div class="feedback-header">
+                                    <h4>
+                                        {for thematic.icon.iter().map(|icon| html! {
+                                            <span class="thematic-icon" aria-hidden="true">{icon}</span>
+                                        })}
+                                        {&thematic.name}
+                                    </h4>
+                                    <span class="feedback-score">{(texts.score)(thematic.score)}</span>
                                 </div>
-                            }
+                                <p class="feedback-completion">{(texts.completion)(&thematic.stats)}</p>
+                                <p class="feedback-message">{thematic.feedback.clone().unwrap_or_default()}</p>
+                                if !thematic.dimensions.is_empty() {
+                                    <button
+                                        class="dimensions-toggle"
+                                        aria-expanded={(*selected == Some(index)).to_string()}
+                                        onclick={
+                                            let toggle_thematic = toggle_thematic.clone();
+                                            Callback::from(move |_| toggle_thematic.emit(index))
+                                        }
+                                    >
+                                        {texts.show_dimensions}
+                                    </button>
+                                }
+                            </div>
                         })}
                     </div>
                 </div>
 
+                if !props.results.action_plan.is_empty() {
+                    <div class="action-plan">
+                        <h3>{texts.action_plan}</h3>
+                        <p class="action-plan-intro">
+                            {texts.action_plan_intro}
+                        </p>
+                        <ol class="action-list">
+                            {for props.results.action_plan.iter().map(|item| render_action_item(texts, item))}
+                        </ol>
+                    </div>
+                }
+
+                {if props.share_url.is_some() {
+                    html! {
+                        <div class="share-section">
+                            <p>{texts.share_intro}</p>
+                            <button onclick={on_copy_link} class="btn btn-secondary">
+                                {if *copied { texts.link_copied } else { texts.copy_link }}
+                            </button>
+                        </div>
+                    }
+                } else {
+                    html! {}
+                }}
+
+                {for props.results.eligibility.iter().map(|eligibility| render_eligibility(texts, eligibility))}
+
                 <div class="cta-section">
-                    <h3>{"Prêt à passer à l'étape suivante ?"}</h3>
-                    <p>{"Le programme Start to Scale peut vous aider à structurer votre croissance et accélérer votre développement."}</p>
+                    <h3>{texts.cta_title}</h3>
+                    <p>{texts.cta_text}</p>
                     <button onclick={on_contact} class="btn btn-primary">
-                        {"Être contacté pour plus d'informations"}
+                        {texts.cta_button}
                     </button>
                 </div>
             </div>
@@ -157,7 +255,133 @@ pub fn ResultsScreen(props: &Props) -> Html {
     }
 }
 
-fn draw_radar_chart(canvas: &HtmlCanvasElement, scores: &std::collections::HashMap<String, f64>) {
+/// Stade de maturité atteint, ses critères remplis et ceux qui manquent pour
+/// le stade suivant
+fn render_maturity(texts: &Texts, maturity: &MaturityResult) -> Html {
+    let stage = maturity.stage();
+    let matched = maturity.matched();
+    html! {
+        <section class="maturity-stage">
+            <span class="maturity-stage-label">{texts.maturity_stage}</span>
+            <span class="maturity-stage-name">
+                {stage.map_or(texts.no_stage_reached, |stage| stage.name.as_str())}
+            </span>
+            {for stage.and_then(|stage| stage.description.as_ref()).map(|description| html! {
+                <p class="maturity-stage-description">{description}</p>
+            })}
+            if !matched.is_empty() {
+                <h4>{texts.matched_criteria}</h4>
+                <ul class="criteria-list">
+                    {for matched.iter().map(|check| html! {
+                        <li class="criterion met">{(texts.criterion)(&check.criterion)}</li>
+                    })}
+                </ul>
+            }
+            {for maturity.next_stage().map(|next| html! {
+                <>
+                    <h4>{(texts.next_stage)(&next.name)}</h4>
+                    <ul class="criteria-list">
+                        {for maturity.missing().into_iter().map(|check| html! {
+                            <li class="criterion missing">{(texts.criterion)(&check.criterion)}</li>
+                        })}
+                    </ul>
+                </>
+            })}
+        </section>
+    }
+}
+
+/// Verdict d'éligibilité au programme et détail des critères évalués
+fn render_eligibility(texts: &Texts, eligibility: &EligibilityResult) -> Html {
+    let verdict_class = match eligibility.verdict {
+        Verdict::Eligible => "eligible",
+        Verdict::EligibleWithConditions => "eligible-with-conditions",
+        Verdict::NotYet => "not-yet",
+    };
+    let render_checks = |title: &'static str, checks: &[CriterionCheck]| {
+        if checks.is_empty() {
+            return html! {};
+        }
+        html! {
+            <>
+                <h4>{title}</h4>
+                <ul class="criteria-list">
+                    {for checks.iter().map(|check| html! {
+                        <li class={classes!("criterion", if check.met { "met" } else { "missing" })}>
+                            {(texts.criterion)(&check.criterion)}
+                        </li>
+                    })}
+                </ul>
+            </>
+        }
+    };
+    html! {
+        <section class={classes!("eligibility", verdict_class)}>
+            <h3>{texts.eligibility_title}</h3>
+            <span class="eligibility-verdict">{(texts.verdict)(eligibility.verdict)}</span>
+            <p>{(texts.verdict_explanation)(eligibility.verdict)}</p>
+            {render_checks(texts.required_criteria, &eligibility.required)}
+            {render_checks(texts.condition_criteria, &eligibility.conditions)}
+        </section>
+    }
+}
+
+/// Scores des sous-dimensions d'une thématique, affichés sous le graphique
+fn render_dimensions(
+    texts: &Texts,
+    thematic: &ThematicResult,
+    on_close: Callback<MouseEvent>,
+) -> Html {
+    html! {
+        <section class="dimension-drilldown" style={format!("--thematic-color: {}", thematic.color)}>
+            <div class="dimension-drilldown-header">
+                <h4>{(texts.dimensions_title)(&thematic.name)}</h4>
+                <button class="dimension-drilldown-close" onclick={on_close}>{texts.close}</button>
+            </div>
+            <ul class="dimension-list">
+                {for thematic.dimensions.iter().map(|dimension| html! {
+                    <li class="dimension-item">
+                        <div class="dimension-header">
+                            <span class="dimension-name">{&dimension.name}</span>
+                            <span class="dimension-score">{(texts.score)(dimension.score)}</span>
+                        </div>
+                        <div class="dimension-bar" aria-hidden="true">
+                            <div class="dimension-bar-fill" style={format!("width: {}%", dimension.score.unwrap_or_default().round())}></div>
+                        </div>
+                        <p class="feedback-completion">{(texts.completion)(&dimension.stats)}</p>
+                    </li>
+                })}
+            </ul>
+        </section>
+    }
+}
+
+/// Index de l'axe du radar le plus proche du point cliqué ; `None` au centre
+fn axis_at(canvas: &HtmlCanvasElement, event: &MouseEvent, count: usize) -> Option<usize> {
+    if count == 0 || canvas.client_width() == 0 {
+        return None;
+    }
+    // Le canvas peut être redimensionné par le CSS
+    let scale = canvas.width() as f64 / canvas.client_width() as f64;
+    let x = event.offset_x() as f64 * scale - canvas.width() as f64 / 2.0;
+    let y = event.offset_y() as f64 * scale - canvas.height() as f64 / 2.0;
+    if x.hypot(y) < 10.0 {
+        return None;
+    }
+    // Le premier axe est vertical, vers le haut
+    let angle = (y.atan2(x) + std::f64::consts::FRAC_PI_2).rem_euclid(std::f64::consts::TAU);
+    let step = std::f64::consts::TAU / count as f64;
+    Some((angle / step).round() as usize % count)
+}
+
+/// Graphique radar des thématiques ; une thématique non évaluée garde son axe
+/// mais n'a pas de point, le polygone reliant les seules thématiques évaluées
+fn draw_radar_chart(
+    canvas: &HtmlCanvasElement,
+    texts: &Texts,
+    thematics: &[ThematicResult],
+    selected: Option<usize>,
+) {
     let container = canvas.parent_element().unwrap();
     let container_width = container.client_width();
     let max_size = container_width.min(600) as u32;
@@ -189,25 +413,25 @@ fn draw_radar_chart(canvas: &HtmlCanvasElement, scores: &std::collections::HashM
         ctx.stroke();
     }
 
-    // Dessiner les axes
-    let thematics: Vec<&String> = scores.keys().collect();
+    // Dessiner les axes, dans l'ordre du questionnaire
     let angle_step = (std::f64::consts::PI * 2.0) / thematics.len() as f64;
 
-    ctx.set_stroke_style_str("#999");
     ctx.set_line_width(1.0);
     for (index, thematic) in thematics.iter().enumerate() {
         let angle = (index as f64 * angle_step) - std::f64::consts::PI / 2.0;
         let x = center_x + angle.cos() * radius;
         let y = center_y + angle.sin() * radius;
 
+        // Chaque axe prend la couleur de sa thématique ; l'axe détaillé est épaissi
+        ctx.set_stroke_style_str(&thematic.color);
+        ctx.set_line_width(if selected == Some(index) { 3.0 } else { 1.0 });
         ctx.begin_path();
         ctx.move_to(center_x, center_y);
         ctx.line_to(x, y);
         ctx.stroke();
 
         // Labels avec scores
-        let score = scores.get(*thematic).unwrap_or(&0.0);
-        let score_text = format!("{}%", score.round() as u32);
+        let score_text = (texts.score)(thematic.score);
 
         // Label de la thématique
         ctx.set_fill_style_str("#333");
@@ -216,10 +440,14 @@ fn draw_radar_chart(canvas: &HtmlCanvasElement, scores: &std::collections::HashM
         ctx.set_text_baseline("middle");
         let label_x = center_x + angle.cos() * (radius + 35.0);
         let label_y = center_y + angle.sin() * (radius + 35.0);
-        ctx.fill_text(thematic, label_x, label_y).unwrap();
-
-        // Score en rouge, plus grand
-        ctx.set_fill_style_str("#d32f2f");
+        let label = match &thematic.icon {
+            Some(icon) => format!("{} {}", icon, thematic.name),
+            None => thematic.name.clone(),
+        };
+        ctx.fill_text(&label, label_x, label_y).unwrap();
+
+        // Score dans la couleur de la thématique, plus grand
+        ctx.set_fill_style_str(&thematic.color);
         ctx.set_font("bold 16px Arial");
         ctx.set_text_baseline("middle");
         let score_y = label_y + 18.0;
@@ -232,17 +460,24 @@ fn draw_radar_chart(canvas: &HtmlCanvasElement, scores: &std::collections::HashM
     ctx.set_line_width(2.0);
     ctx.begin_path();
 
-    for (index, thematic) in thematics.iter().enumerate() {
-        let score = scores.get(*thematic).unwrap_or(&0.0);
-        let angle = (index as f64 * angle_step) - std::f64::consts::PI / 2.0;
-        let r = (radius * score) / 100.0;
-        let x = center_x + angle.cos() * r;
-        let y = center_y + angle.sin() * r;
-
+    let points: Vec<(&ThematicResult, f64, f64)> = thematics
+        .iter()
+        .enumerate()
+        .filter_map(|(index, thematic)| {
+            let angle = (index as f64 * angle_step) - std::f64::consts::PI / 2.0;
+            let r = (radius * thematic.score?) / 100.0;
+            Some((
+                thematic,
+                center_x + angle.cos() * r,
+                center_y + angle.sin() * r,
+            ))
+        })
+        .collect();
+    for (index, (_, x, y)) in points.iter().enumerate() {
         if index == 0 {
-            ctx.move_to(x, y);
+            ctx.move_to(*x, *y);
         } else {
-            ctx.line_to(x, y);
+            ctx.line_to(*x, *y);
         }
     }
 
@@ -251,14 +486,8 @@ fn draw_radar_chart(canvas: &HtmlCanvasElement, scores: &std::collections::HashM
     ctx.stroke();
 
     // Points sur les axes
-    ctx.set_fill_style_str("#d32f2f");
-    for (index, thematic) in thematics.iter().enumerate() {
-        let score = scores.get(*thematic).unwrap_or(&0.0);
-        let angle = (index as f64 * angle_step) - std::f64::consts::PI / 2.0;
-        let r = (radius * score) / 100.0;
-        let x = center_x + angle.cos() * r;
-        let y = center_y + angle.sin() * r;
-
+    for (thematic, x, y) in points {
+        ctx.set_fill_style_str(&thematic.color);
         ctx.begin_path();
         ctx.arc(x, y, 4.0, 0.0, std::f64::consts::PI * 2.0).unwrap();
         ctx.fill();
@@ -0,0 +1,114 @@
+use crate::i18n::{self, Locale};
+use crate::models::QuestionData;
+use crate::survey::Survey;
+use std::rc::Rc;
+use yew::prelude::*;
+
+#[derive(Properties)]
+pub struct Props {
+    pub locale: Locale,
+    pub survey: Rc<Survey>,
+    /// Revient à la question d'index donné pour la modifier
+    pub on_edit: Callback<usize>,
+    pub on_show_results: Callback<()>,
+    pub on_go_to_welcome: Callback<()>,
+}
+
+impl PartialEq for Props {
+    fn eq(&self, other: &Self) -> bool {
+        self.locale == other.locale && Rc::ptr_eq(&self.survey, &other.survey)
+    }
+}
+
+/// Questions visibles regroupées par thématique, dans l'ordre du questionnaire
+/// et selon les mêmes sections que la navigation de l'écran des questions
+fn group_by_thematic(survey: &Survey) -> Vec<(String, Vec<QuestionData>)> {
+    survey
+        .thematic_sections()
+        .into_iter()
+        .map(|section| {
+            let questions: Vec<QuestionData> = section
+                .questions
+                .filter(|index| survey.is_visible(*index))
+                .map(|index| survey.get_question(index))
+                .collect();
+            (section.name, questions)
+        })
+        .filter(|(_, questions)| !questions.is_empty())
+        .collect()
+}
+
+#[function_component]
+pub fn ReviewScreen(props: &Props) -> Html {
+    let texts = i18n::texts(props.locale);
+    let groups = group_by_thematic(&props.survey);
+    let unanswered = groups
+        .iter()
+        .flat_map(|(_, questions)| questions)
+        .filter(|q| q.answer.is_none())
+        .count();
+
+    let on_show_results = {
+        let callback = props.on_show_results.clone();
+        Callback::from(move |_| callback.emit(()))
+    };
+
+    let on_logo_click = {
+        let callback = props.on_go_to_welcome.clone();
+        Callback::from(move |_| callback.emit(()))
+    };
+
+    html! {
+        <div class="screen active">
+            <div class="container review-container">
+                <div class="questions-header">
+                    <div class="header-logo" onclick={on_logo_click} style="cursor: pointer;">
+                        <img src="hub612-logo.webp" alt="HUB612" />
+                    </div>
+                </div>
+                <h2>{texts.review_title}</h2>
+                <p class="review-intro">{texts.review_intro}</p>
+                if unanswered > 0 {
+                    <div class="questions-notice">{(texts.review_unanswered_count)(unanswered)}</div>
+                }
+
+                {for groups.into_iter().map(|(thematic, group)| html! {
+                    <div class="review-thematic">
+                        <h3>{thematic}</h3>
+                        <ul class="review-list">
+                            {for group.into_iter().map(|question_data| {
+                                let callback = props.on_edit.clone();
+                                let index = question_data.index;
+                                let (answer, class) = match question_data.answer_label {
+                                    Some(label) => (label, None),
+                                    None if question_data.skipped => {
+                                        (texts.review_skipped.to_string(), Some("unanswered"))
+                                    }
+                                    None => (texts.review_unanswered.to_string(), Some("unanswered")),
+                                };
+                                html! {
+                                    <li class={classes!("review-item", class)}>
+                                        <button
+                                            onclick={Callback::from(move |_| callback.emit(index))}
+                                            class="review-question"
+                                            title={texts.review_edit}
+                                        >
+                                            <span class="review-question-text">{question_data.question.text}</span>
+                                            <span class="review-answer">{answer}</span>
+                                        </button>
+                                    </li>
+                                }
+                            })}
+                        </ul>
+                    </div>
+                })}
+
+                <div class="navigation">
+                    <button onclick={on_show_results} class="btn btn-primary">
+                        {texts.show_results}
+                    </button>
+                </div>
+            </div>
+        </div>
+    }
+}
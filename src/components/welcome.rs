@@ -1,35 +1,116 @@
+use crate::i18n::{self, Locale};
+use crate::models::SurveyTemplate;
 use yew::prelude::*;
 
 #[derive(Properties, PartialEq)]
 pub struct Props {
-    pub on_start: Callback<()>,
+    pub locale: Locale,
+    pub on_locale: Callback<Locale>,
+    /// Questionnaire en cours de chargement
+    pub loading: bool,
+    /// Questionnaires proposés, le premier étant celui par défaut
+    pub templates: Vec<SurveyTemplate>,
+    /// Démarre le questionnaire dont l'identifiant est transmis
+    pub on_start: Callback<String>,
+    /// Titre du questionnaire d'un audit en cours, s'il y en a un
+    pub saved_session: Option<String>,
+    pub on_resume: Callback<()>,
+    pub on_discard: Callback<()>,
 }
 
 #[function_component]
 pub fn WelcomeScreen(props: &Props) -> Html {
-    let on_click = {
+    let texts = i18n::texts(props.locale);
+    let start_button = |template_id: String, label: &'static str| {
         let callback = props.on_start.clone();
-        Callback::from(move |_| callback.emit(()))
+        html! {
+            <button
+                onclick={Callback::from(move |_| callback.emit(template_id.clone()))}
+                class="btn btn-primary"
+                disabled={props.loading}
+            >
+                {if props.loading {
+                    texts.loading_questions
+                } else {
+                    label
+                }}
+            </button>
+        }
     };
 
     html! {
         <div class="screen active welcome-screen">
             <div class="container welcome-container">
+                <div class="locale-switch">
+                    {for Locale::ALL.into_iter().map(|locale| {
+                        let callback = props.on_locale.clone();
+                        html! {
+                            <button
+                                onclick={Callback::from(move |_| callback.emit(locale))}
+                                class={classes!("locale-btn", (locale == props.locale).then_some("active"))}
+                                title={i18n::texts(locale).language_name}
+                                lang={locale.code()}
+                            >
+                                {locale.code().to_uppercase()}
+                            </button>
+                        }
+                    })}
+                </div>
                 <div class="logo">
                     <img src="hub612-logo.webp" alt="HUB612" />
                 </div>
-                <h2>{"Mini Audit Start to Scale"}</h2>
+                <h2>{texts.app_title}</h2>
                 <p class="intro">
-                    {"Testez votre startup en 5 minutes !"}
+                    {texts.tagline}
                     <br />
-                    {"Répondez à quelques questions pour évaluer votre maturité sur les thématiques clés du programme Start to Scale."}
+                    {texts.intro}
                 </p>
                 <div class="welcome-image">
-                    <img src="laundry-1834_256.gif" alt="Mini audit Start to Scale" />
+                    <img src="laundry-1834_256.gif" alt={texts.welcome_image_alt} />
                 </div>
-                <button onclick={on_click} class="btn btn-primary">
-                    {"Commencer l'audit"}
-                </button>
+                {if let Some(ref title) = props.saved_session {
+                    let on_resume = {
+                        let callback = props.on_resume.clone();
+                        Callback::from(move |_| callback.emit(()))
+                    };
+                    let on_discard = {
+                        let callback = props.on_discard.clone();
+                        Callback::from(move |_| callback.emit(()))
+                    };
+                    html! {
+                        <div class="resume-session">
+                            <p>{(texts.resume_prompt)(title)}</p>
+                            <div class="resume-actions">
+                                <button onclick={on_resume} class="btn btn-primary" disabled={props.loading}>
+                                    {texts.resume_audit}
+                                </button>
+                                <button onclick={on_discard} class="btn btn-secondary">
+                                    {texts.discard_session}
+                                </button>
+                            </div>
+                        </div>
+                    }
+                } else {
+                    html! {}
+                }}
+                {match props.templates.as_slice() {
+                    [] => html! {},
+                    [template] => start_button(template.id.clone(), texts.start_audit),
+                    templates => html! {
+                        <div class="template-list">
+                            {for templates.iter().map(|template| html! {
+                                <div class="template-card">
+                                    <h3>{&template.title}</h3>
+                                    <p class="template-description">{&template.description}</p>
+                                    <p class="template-meta">
+                                        {(texts.template_meta)(template.thematics.len(), &template.version)}
+                                    </p>
+                                    {start_button(template.id.clone(), texts.start_this_audit)}
+                                </div>
+                            })}
+                        </div>
+                    },
+                }}
                 <footer class="welcome-footer">
                     <p class="footer-text">
                         {"Made with "}
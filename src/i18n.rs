@@ -0,0 +1,427 @@
+pub use hub_survey_core::i18n::Locale;
+
+use crate::models::{Level, Verdict};
+use crate::storage;
+use hub_survey_core::i18n::{self as survey_i18n, Texts as SurveyTexts};
+use std::ops::Deref;
+
+/// Textes de l'interface dans une langue. Chaque langue renseigne tous les
+/// champs : un texte manquant est une erreur de compilation.
+pub struct Texts {
+    /// Textes du questionnaire et des résultats, communs avec l'outil en ligne
+    /// de commande et accessibles directement (`texts.yes`)
+    survey: &'static SurveyTexts,
+
+    pub language_name: &'static str,
+
+    // Accueil
+    pub app_title: &'static str,
+    pub tagline: &'static str,
+    pub intro: &'static str,
+    pub welcome_image_alt: &'static str,
+    pub loading_questions: &'static str,
+    pub start_audit: &'static str,
+    pub start_this_audit: &'static str,
+    pub template_meta: fn(usize, &str) -> String,
+    pub resume_prompt: fn(&str) -> String,
+    pub resume_audit: &'static str,
+    pub discard_session: &'static str,
+
+    // Questionnaire invalide
+    pub bank_error_title: &'static str,
+    pub bank_error_intro: &'static str,
+    pub error_file: &'static str,
+    pub error_position: &'static str,
+    pub error_reason: &'static str,
+    pub error_location: fn(Option<usize>, Option<usize>) -> String,
+    /// Raison affichée lorsqu'aucun questionnaire n'a pu être chargé
+    pub no_questionnaire: &'static str,
+
+    // Questions
+    pub keyboard_hint: &'static str,
+    /// Écran des questions ou récapitulatif affiché sans questionnaire en cours
+    pub survey_not_started: &'static str,
+    pub previous: &'static str,
+    pub next: &'static str,
+    pub show_results: &'static str,
+    pub clear_answer: &'static str,
+    pub thematic_nav: &'static str,
+    pub skipped_question: &'static str,
+    pub unanswered_notice: fn(usize) -> String,
+    pub review_answers: &'static str,
+
+    // Récapitulatif
+    pub review_title: &'static str,
+    pub review_intro: &'static str,
+    pub review_unanswered: &'static str,
+    pub review_skipped: &'static str,
+    pub review_edit: &'static str,
+    pub review_unanswered_count: fn(usize) -> String,
+
+    // Résultats
+    pub results_title: &'static str,
+    pub results_intro: &'static str,
+    pub no_results: &'static str,
+    pub matched_criteria: &'static str,
+    pub radar_label: &'static str,
+    pub radar_table_caption: &'static str,
+    pub thematic_column: &'static str,
+    pub score_column: &'static str,
+    pub radar_drilldown_hint: &'static str,
+    pub show_dimensions: &'static str,
+    pub dimensions_title: fn(&str) -> String,
+    pub close: &'static str,
+    pub action_plan_intro: &'static str,
+    pub action_tags: fn(Level, Level) -> String,
+    pub verdict_explanation: fn(Verdict) -> &'static str,
+    pub required_criteria: &'static str,
+    pub condition_criteria: &'static str,
+    pub share_intro: &'static str,
+    pub copy_link: &'static str,
+    pub link_copied: &'static str,
+    pub cta_title: &'static str,
+    pub cta_text: &'static str,
+    pub cta_button: &'static str,
+
+    // Contact
+    pub contact_title: &'static str,
+    pub contact_intro: &'static str,
+    pub error_prefix: &'static str,
+    pub startup_name_label: &'static str,
+    pub firstname_label: &'static str,
+    pub lastname_label: &'static str,
+    pub email_label: &'static str,
+    pub email_hint: &'static str,
+    pub phone_label: &'static str,
+    pub phone_hint: &'static str,
+    pub message_label: &'static str,
+    pub back_to_results: &'static str,
+    pub sending: &'static str,
+    pub send: &'static str,
+    pub thanks_title: &'static str,
+    pub thanks_text: &'static str,
+
+    // Validation et envoi du formulaire
+    pub startup_name_required: &'static str,
+    pub firstname_required: &'static str,
+    pub lastname_required: &'static str,
+    pub email_required: &'static str,
+    pub invalid_email: &'static str,
+    pub invalid_phone: &'static str,
+    pub invalid_response: &'static str,
+    pub unknown_error: &'static str,
+    pub read_error: &'static str,
+    pub request_error: fn(&str) -> String,
+    pub http_error: fn(u16) -> String,
+    pub network_error: fn(&str) -> String,
+}
+
+impl Deref for Texts {
+    type Target = SurveyTexts;
+
+    fn deref(&self) -> &SurveyTexts {
+        self.survey
+    }
+}
+
+/// Textes de l'interface dans la langue choisie
+pub fn texts(locale: Locale) -> &'static Texts {
+    match locale {
+        Locale::Fr => &FR,
+        Locale::En => &EN,
+    }
+}
+
+fn fr_level(level: Level) -> &'static str {
+    match level {
+        Level::Faible => "faible",
+        Level::Moyen => "moyen",
+        Level::Eleve => "élevé",
+    }
+}
+
+fn en_level(level: Level) -> &'static str {
+    match level {
+        Level::Faible => "low",
+        Level::Moyen => "medium",
+        Level::Eleve => "high",
+    }
+}
+
+const FR: Texts = Texts {
+    survey: &survey_i18n::FR,
+
+    language_name: "Français",
+
+    app_title: "Mini Audit Start to Scale",
+    tagline: "Testez votre startup en 5 minutes !",
+    intro: "Répondez à quelques questions pour évaluer votre maturité sur les thématiques clés du programme Start to Scale.",
+    welcome_image_alt: "Mini audit Start to Scale",
+    loading_questions: "Chargement du questionnaire...",
+    start_audit: "Commencer l'audit",
+    start_this_audit: "Commencer cet audit",
+    template_meta: |thematics, version| format!("{} thématiques · version {}", thematics, version),
+    resume_prompt: |title| format!("Vous avez un audit « {} » en cours.", title),
+    resume_audit: "Reprendre l'audit",
+    discard_session: "Effacer",
+
+    bank_error_title: "Le questionnaire n'a pas pu être chargé",
+    bank_error_intro: "Un fichier du questionnaire est invalide. Merci de réessayer plus tard ou de contacter l'équipe Hub612.",
+    error_file: "Fichier : ",
+    error_position: "Position : ",
+    error_reason: "Raison : ",
+    error_location: |line, column| match (line, column) {
+        (Some(line), Some(column)) => format!("ligne {}, colonne {}", line, column),
+        (Some(line), None) => format!("ligne {}", line),
+        _ => "position inconnue".to_string(),
+    },
+    no_questionnaire: "aucun questionnaire disponible",
+
+    keyboard_hint: "Clavier : O (oui), N (non), ? (je ne sais pas), chiffres pour les échelles et les choix, ← → pour changer de question.",
+    survey_not_started: "Erreur : aucun questionnaire en cours",
+    previous: "Précédent",
+    next: "Suivant",
+    show_results: "Voir les résultats",
+    clear_answer: "Effacer ma réponse",
+    thematic_nav: "Thématiques du questionnaire",
+    skipped_question: "Vous avez passé cette question.",
+    unanswered_notice: |count| {
+        format!(
+            "Répondez aux {} question(s) restante(s) pour afficher vos résultats.",
+            count
+        )
+    },
+    review_answers: "Vérifier mes réponses",
+
+    review_title: "Récapitulatif de vos réponses",
+    review_intro: "Vérifiez vos réponses avant d'afficher vos résultats. Cliquez sur une question pour la modifier.",
+    review_unanswered: "Sans réponse",
+    review_skipped: "Passée",
+    review_edit: "Modifier cette réponse",
+    review_unanswered_count: |count| format!("{} question(s) sans réponse.", count),
+
+    results_title: "Vos résultats",
+    results_intro: "Voici votre profil de maturité sur les différentes thématiques du programme Start to Scale.",
+    no_results: "Aucun résultat disponible",
+    matched_criteria: "Critères remplis",
+    radar_label: "Graphique radar des scores par thématique, détaillés dans le tableau qui suit",
+    radar_table_caption: "Scores par thématique",
+    thematic_column: "Thématique",
+    score_column: "Score",
+    radar_drilldown_hint: "Cliquez sur un axe du graphique pour afficher le détail de ses sous-dimensions.",
+    show_dimensions: "Détail par sous-dimension",
+    dimensions_title: |thematic| format!("{} : sous-dimensions", thematic),
+    close: "Fermer",
+    action_plan_intro: "Les prochaines étapes recommandées d'après vos réponses, par ordre de priorité.",
+    action_tags: |impact, effort| {
+        format!(
+            "Impact {} · Effort {}",
+            fr_level(impact),
+            fr_level(effort)
+        )
+    },
+    verdict_explanation: |verdict| match verdict {
+        Verdict::Eligible => "Votre startup remplit l'ensemble des critères du programme : notre équipe peut étudier votre candidature dès maintenant.",
+        Verdict::EligibleWithConditions => "Votre startup remplit les critères indispensables du programme. Les conditions restantes seront à travailler pendant l'accompagnement.",
+        Verdict::NotYet => "Certains critères indispensables ne sont pas encore remplis. Travaillez-les en priorité : notre équipe reste disponible pour en discuter.",
+    },
+    required_criteria: "Critères indispensables",
+    condition_criteria: "Conditions",
+    share_intro: "Conservez ou partagez vos résultats grâce à ce lien, qui contient vos réponses.",
+    copy_link: "Copier le lien",
+    link_copied: "Lien copié !",
+    cta_title: "Prêt à passer à l'étape suivante ?",
+    cta_text: "Le programme Start to Scale peut vous aider à structurer votre croissance et accélérer votre développement.",
+    cta_button: "Être contacté pour plus d'informations",
+
+    contact_title: "Contactez-nous",
+    contact_intro: "Remplissez ce formulaire et notre équipe vous recontactera rapidement pour discuter du programme Start to Scale.",
+    error_prefix: "Erreur : ",
+    startup_name_label: "Nom de votre startup *",
+    firstname_label: "Votre prénom *",
+    lastname_label: "Votre nom *",
+    email_label: "Votre email *",
+    email_hint: "Format d'email invalide (exemple: nom@exemple.com)",
+    phone_label: "Téléphone (optionnel)",
+    phone_hint: "Format de téléphone invalide (exemple: +33 6 12 34 56 78 ou 06 12 34 56 78)",
+    message_label: "Message (optionnel)",
+    back_to_results: "Retour aux résultats",
+    sending: "Envoi en cours...",
+    send: "Envoyer",
+    thanks_title: "✓ Merci !",
+    thanks_text: "Votre demande a été envoyée. Notre équipe vous recontactera dans les plus brefs délais.",
+
+    startup_name_required: "Le nom de la startup est requis",
+    firstname_required: "Votre prénom est requis",
+    lastname_required: "Votre nom est requis",
+    email_required: "L'email est requis",
+    invalid_email: "Format d'email invalide",
+    invalid_phone: "Format de téléphone invalide. Format attendu : +33 6 12 34 56 78 ou 06 12 34 56 78",
+    invalid_response: "Réponse invalide",
+    unknown_error: "Erreur inconnue",
+    read_error: "Erreur lors de la lecture de la réponse",
+    request_error: |err| format!("Erreur lors de la création de la requête: {}", err),
+    http_error: |status| format!("Erreur HTTP: {}", status),
+    network_error: |err| format!("Erreur réseau: {}", err),
+};
+
+const EN: Texts = Texts {
+    survey: &survey_i18n::EN,
+
+    language_name: "English",
+
+    app_title: "Start to Scale Mini Audit",
+    tagline: "Test your startup in 5 minutes!",
+    intro: "Answer a few questions to assess your maturity on the key topics of the Start to Scale programme.",
+    welcome_image_alt: "Start to Scale mini audit",
+    loading_questions: "Loading the questionnaire...",
+    start_audit: "Start the audit",
+    start_this_audit: "Start this audit",
+    template_meta: |thematics, version| format!("{} topics · version {}", thematics, version),
+    resume_prompt: |title| format!("You have a “{}” audit in progress.", title),
+    resume_audit: "Resume the audit",
+    discard_session: "Discard",
+
+    bank_error_title: "The questionnaire could not be loaded",
+    bank_error_intro: "A questionnaire file is invalid. Please try again later or contact the Hub612 team.",
+    error_file: "File: ",
+    error_position: "Position: ",
+    error_reason: "Reason: ",
+    error_location: |line, column| match (line, column) {
+        (Some(line), Some(column)) => format!("line {}, column {}", line, column),
+        (Some(line), None) => format!("line {}", line),
+        _ => "unknown position".to_string(),
+    },
+    no_questionnaire: "no questionnaire available",
+
+    keyboard_hint: "Keyboard: Y (yes), N (no), ? (I don't know), digits for scales and choices, ← → to change question.",
+    survey_not_started: "Error: no questionnaire in progress",
+    previous: "Previous",
+    next: "Next",
+    show_results: "See the results",
+    clear_answer: "Clear my answer",
+    thematic_nav: "Questionnaire topics",
+    skipped_question: "You skipped this question.",
+    unanswered_notice: |count| {
+        format!(
+            "Answer the {} remaining question(s) to see your results.",
+            count
+        )
+    },
+    review_answers: "Review my answers",
+
+    review_title: "Review your answers",
+    review_intro: "Check your answers before seeing your results. Click a question to change it.",
+    review_unanswered: "Not answered",
+    review_skipped: "Skipped",
+    review_edit: "Change this answer",
+    review_unanswered_count: |count| format!("{} unanswered question(s).", count),
+
+    results_title: "Your results",
+    results_intro: "Here is your maturity profile across the topics of the Start to Scale programme.",
+    no_results: "No results available",
+    matched_criteria: "Criteria met",
+    radar_label: "Radar chart of the scores per topic, detailed in the following table",
+    radar_table_caption: "Scores per topic",
+    thematic_column: "Topic",
+    score_column: "Score",
+    radar_drilldown_hint: "Click an axis of the chart to see its sub-dimension breakdown.",
+    show_dimensions: "Breakdown by sub-dimension",
+    dimensions_title: |thematic| format!("{}: sub-dimensions", thematic),
+    close: "Close",
+    action_plan_intro: "The next steps we recommend based on your answers, in order of priority.",
+    action_tags: |impact, effort| {
+        format!(
+            "{} impact · {} effort",
+            capitalize(en_level(impact)),
+            capitalize(en_level(effort))
+        )
+    },
+    verdict_explanation: |verdict| match verdict {
+        Verdict::Eligible => "Your startup meets every criterion of the programme: our team can review your application right away.",
+        Verdict::EligibleWithConditions => "Your startup meets the essential criteria of the programme. The remaining conditions will be worked on during the programme.",
+        Verdict::NotYet => "Some essential criteria are not met yet. Focus on them first: our team is happy to discuss them with you.",
+    },
+    required_criteria: "Essential criteria",
+    condition_criteria: "Conditions",
+    share_intro: "Keep or share your results with this link, which contains your answers.",
+    copy_link: "Copy the link",
+    link_copied: "Link copied!",
+    cta_title: "Ready for the next step?",
+    cta_text: "The Start to Scale programme can help you structure your growth and speed up your development.",
+    cta_button: "Get in touch for more information",
+
+    contact_title: "Contact us",
+    contact_intro: "Fill in this form and our team will get back to you shortly to discuss the Start to Scale programme.",
+    error_prefix: "Error: ",
+    startup_name_label: "Your startup's name *",
+    firstname_label: "Your first name *",
+    lastname_label: "Your last name *",
+    email_label: "Your email *",
+    email_hint: "Invalid email format (example: name@example.com)",
+    phone_label: "Phone (optional)",
+    phone_hint: "Invalid phone format (example: +33 6 12 34 56 78 or 06 12 34 56 78)",
+    message_label: "Message (optional)",
+    back_to_results: "Back to the results",
+    sending: "Sending...",
+    send: "Send",
+    thanks_title: "✓ Thank you!",
+    thanks_text: "Your request has been sent. Our team will get back to you as soon as possible.",
+
+    startup_name_required: "The startup name is required",
+    firstname_required: "Your first name is required",
+    lastname_required: "Your last name is required",
+    email_required: "The email is required",
+    invalid_email: "Invalid email format",
+    invalid_phone: "Invalid phone format. Expected format: +33 6 12 34 56 78 or 06 12 34 56 78",
+    invalid_response: "Invalid response",
+    unknown_error: "Unknown error",
+    read_error: "Error while reading the response",
+    request_error: |err| format!("Error while creating the request: {}", err),
+    http_error: |status| format!("HTTP error: {}", status),
+    network_error: |err| format!("Network error: {}", err),
+};
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Langue demandée dans l'URL, sinon choisie précédemment, sinon celle du
+/// navigateur ; le français à défaut
+pub fn detect_locale() -> Locale {
+    let window = web_sys::window();
+    let from_url = window
+        .as_ref()
+        .and_then(|window| window.location().search().ok())
+        .and_then(|search| {
+            search
+                .trim_start_matches('?')
+                .split('&')
+                .find_map(|param| param.strip_prefix("lang="))
+                .and_then(Locale::from_code)
+        });
+
+    from_url
+        .or_else(storage::load_locale)
+        .or_else(|| {
+            window?
+                .navigator()
+                .language()
+                .and_then(|language| Locale::from_code(&language))
+        })
+        .unwrap_or_default()
+}
+
+/// Renseigne l'attribut `lang` de la page, utilisé par les lecteurs d'écran
+pub fn apply_to_document(locale: Locale) {
+    if let Some(root) = web_sys::window()
+        .and_then(|window| window.document())
+        .and_then(|document| document.document_element())
+    {
+        let _ = root.set_attribute("lang", locale.code());
+    }
+}
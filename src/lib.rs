@@ -1,7 +1,11 @@
 mod app;
 mod components;
-mod models;
-mod survey;
+mod i18n;
+mod loader;
+mod share;
+mod storage;
+
+use hub_survey_core::{models, scoring, survey};
 
 use wasm_bindgen::prelude::*;
 
@@ -0,0 +1,70 @@
+use crate::i18n::Locale;
+use crate::models::QuestionCatalog;
+use wasm_bindgen::JsCast;
+use wasm_bindgen_futures::JsFuture;
+
+/// Catalogue des questionnaires publié avec l'application. `None` désactive le
+/// chargement à l'exécution : seul le questionnaire intégré est alors utilisé.
+pub const QUESTIONS_CATALOG_URL: Option<&str> = Some("/questions/catalog.yaml");
+
+/// Catalogue d'une langue : les traductions sont rangées dans un sous-dossier
+/// du catalogue français (`/questions/en/catalog.yaml`)
+pub fn catalog_url(locale: Locale) -> Option<String> {
+    let url = QUESTIONS_CATALOG_URL?;
+    let (dir, file) = match url.rfind('/') {
+        Some(index) => url.split_at(index + 1),
+        None => ("", url),
+    };
+    Some(format!("{}{}{}", dir, locale.questions_dir(), file))
+}
+
+/// Fichiers bruts du questionnaire : contenu du catalogue et couples
+/// (nom de fichier, contenu YAML) des thématiques qu'il référence
+pub type FetchedQuestions = (String, Vec<(String, String)>);
+
+/// Télécharge le catalogue puis chacune des thématiques référencées par ses
+/// questionnaires. La validation du contenu est laissée à `QuestionBundle`.
+pub async fn fetch_question_sources(catalog_url: &str) -> Result<FetchedQuestions, String> {
+    let catalog_content = fetch_text(catalog_url).await?;
+    let catalog: QuestionCatalog = match serde_yaml::from_str(&catalog_content) {
+        Ok(catalog) => catalog,
+        Err(_) => return Ok((catalog_content, Vec::new())),
+    };
+
+    let base_url = match catalog_url.rfind('/') {
+        Some(index) => &catalog_url[..=index],
+        None => "",
+    };
+
+    let mut files: Vec<(String, String)> = Vec::new();
+    for file in catalog.thematic_files() {
+        let content = fetch_text(&format!("{}{}", base_url, file)).await?;
+        files.push((file.to_string(), content));
+    }
+
+    Ok((catalog_content, files))
+}
+
+async fn fetch_text(url: &str) -> Result<String, String> {
+    let window = web_sys::window().ok_or("Fenêtre du navigateur indisponible")?;
+
+    let response_val = JsFuture::from(window.fetch_with_str(url))
+        .await
+        .map_err(|err| format!("Erreur réseau ({}): {:?}", url, err))?;
+    let response: web_sys::Response = response_val
+        .dyn_into()
+        .map_err(|_| format!("Réponse invalide ({})", url))?;
+
+    if !response.ok() {
+        return Err(format!("Erreur HTTP {} ({})", response.status(), url));
+    }
+
+    let text_promise = response
+        .text()
+        .map_err(|_| format!("Erreur lors de la lecture de la réponse ({})", url))?;
+    JsFuture::from(text_promise)
+        .await
+        .map_err(|_| format!("Erreur lors de la lecture de la réponse ({})", url))?
+        .as_string()
+        .ok_or_else(|| format!("Contenu non textuel ({})", url))
+}
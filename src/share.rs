@@ -0,0 +1,10 @@
+pub use hub_survey_core::share::{encode_fragment, SharedAnswers};
+
+use crate::survey::Survey;
+
+/// Adresse de la page courante suivie du fragment encodant les réponses
+pub fn share_url(survey: &Survey) -> Option<String> {
+    let href = web_sys::window()?.location().href().ok()?;
+    let page = href.split('#').next().unwrap_or_default();
+    Some(format!("{}{}", page, encode_fragment(survey)?))
+}
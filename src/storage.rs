@@ -0,0 +1,68 @@
+use crate::app::{FormData, Screen};
+use crate::i18n::Locale;
+use crate::models::{Answer, SurveyTemplate};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+
+/// Audit en cours, sauvegardé dans le `localStorage` pour survivre à un
+/// rechargement de la page
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SavedSession {
+    pub template_id: String,
+    pub template_version: String,
+    pub answers: HashMap<String, Answer>,
+    /// Questions passées sans réponse
+    #[serde(default)]
+    pub skipped: HashSet<String>,
+    pub current_question_index: usize,
+    pub screen: Screen,
+    pub form_data: FormData,
+}
+
+/// Une clé par questionnaire et par version : une session enregistrée pour une
+/// version antérieure des questions n'est jamais reprise
+fn session_key(template_id: &str, template_version: &str) -> String {
+    format!("hub-survey:session:{}:{}", template_id, template_version)
+}
+
+fn local_storage() -> Option<web_sys::Storage> {
+    web_sys::window()?.local_storage().ok().flatten()
+}
+
+pub fn load_session(template: &SurveyTemplate) -> Option<SavedSession> {
+    let content = local_storage()?
+        .get_item(&session_key(&template.id, &template.version))
+        .ok()
+        .flatten()?;
+    serde_json::from_str(&content).ok()
+}
+
+pub fn save_session(session: &SavedSession) {
+    if let (Some(storage), Ok(content)) = (local_storage(), serde_json::to_string(session)) {
+        // Stockage plein ou désactivé (navigation privée) : l'audit continue sans sauvegarde
+        let _ = storage.set_item(
+            &session_key(&session.template_id, &session.template_version),
+            &content,
+        );
+    }
+}
+
+pub fn clear_session(template_id: &str, template_version: &str) {
+    if let Some(storage) = local_storage() {
+        let _ = storage.remove_item(&session_key(template_id, template_version));
+    }
+}
+
+const LOCALE_KEY: &str = "hub-survey:locale";
+
+/// Langue choisie explicitement avec le sélecteur de langue
+pub fn load_locale() -> Option<Locale> {
+    let code = local_storage()?.get_item(LOCALE_KEY).ok().flatten()?;
+    Locale::from_code(&code)
+}
+
+pub fn save_locale(locale: Locale) {
+    if let Some(storage) = local_storage() {
+        let _ = storage.set_item(LOCALE_KEY, locale.code());
+    }
+}